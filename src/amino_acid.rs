@@ -0,0 +1,174 @@
+// Amino acids and the standard genetic code, translating DNA genes into
+// proteins.
+
+use super::{Base, BaseString};
+use super::dna_base::DNABase;
+use super::translation::{CodonTable, Translation};
+use rand::{Rand, Rng};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum AminoAcid {
+    Ala,
+    Arg,
+    Asn,
+    Asp,
+    Cys,
+    Gln,
+    Glu,
+    Gly,
+    His,
+    Ile,
+    Leu,
+    Lys,
+    Met,
+    Phe,
+    Pro,
+    Ser,
+    Thr,
+    Trp,
+    Tyr,
+    Val,
+}
+
+use self::AminoAcid::*;
+
+pub const AMINO_ACIDS: [AminoAcid; 20] = [Ala, Arg, Asn, Asp, Cys, Gln, Glu, Gly, His, Ile, Leu,
+                                          Lys, Met, Phe, Pro, Ser, Thr, Trp, Tyr, Val];
+
+// One-letter codes in the order of ```AMINO_ACIDS```.
+const CODES: &str = "ARNDCQEGHILKMFPSTWYV";
+
+impl Rand for AminoAcid {
+    fn rand<R: Rng>(rng: &mut R) -> Self {
+        AminoAcid::from_index(rng.gen_range(0, AminoAcid::cardinality()))
+    }
+}
+
+impl Base for AminoAcid {
+    fn succ(self) -> Self {
+        AminoAcid::from_index((self.index() + 1) % AminoAcid::cardinality())
+    }
+
+    /// Parses the one-letter code.
+    fn from_char(c: char) -> Option<Self> {
+        CODES.chars().position(|code| code == c).map(AminoAcid::from_index)
+    }
+
+    fn to_char(self) -> char {
+        CODES.as_bytes()[self.index()] as char
+    }
+
+    // Amino acids do not pair. Each one is its own complement.
+    fn complement(self) -> Self {
+        self
+    }
+
+    fn cardinality() -> usize {
+        AMINO_ACIDS.len()
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        AMINO_ACIDS[index]
+    }
+}
+
+// The standard genetic code. Codon i consists of the bases TCAG[i / 16],
+// TCAG[i / 4 % 4] and TCAG[i % 4]. '*' marks stop codons.
+const STANDARD_CODE: &str = "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+
+/// Translates DNA genes codon by codon into proteins. Translation ends
+/// before the first stop codon.
+#[derive(Debug, Clone)]
+pub struct GeneticCode {
+    codons: CodonTable<DNABase, AminoAcid>,
+    stop_codons: Vec<[DNABase; 3]>,
+    start_codons: Vec<[DNABase; 3]>,
+    // Translation begins at the first start codon of a gene (in any frame).
+    // Genes without start codon yield an empty protein. Otherwise translation
+    // begins with the first base.
+    pub require_start: bool,
+}
+
+impl GeneticCode {
+    /// The standard genetic code with start codon ATG.
+    pub fn standard() -> GeneticCode {
+        let order = [DNABase::T, DNABase::C, DNABase::A, DNABase::G];
+        let mut code = GeneticCode {
+            codons: CodonTable::new(3),
+            stop_codons: Vec::new(),
+            start_codons: vec![[DNABase::A, DNABase::T, DNABase::G]],
+            require_start: true,
+        };
+        for (i, symbol) in STANDARD_CODE.chars().enumerate() {
+            let codon = [order[i / 16], order[i / 4 % 4], order[i % 4]];
+            match AminoAcid::from_char(symbol) {
+                Some(amino_acid) => code.codons.insert(&codon, amino_acid),
+                None => code.stop_codons.push(codon),
+            }
+        }
+        code
+    }
+
+    pub fn amino_acid(&self, codon: &[DNABase]) -> Option<AminoAcid> {
+        self.codons.get(codon)
+    }
+
+    pub fn is_start_codon(&self, codon: &[DNABase]) -> bool {
+        self.start_codons.iter().any(|start| &start[..] == codon)
+    }
+
+    pub fn is_stop_codon(&self, codon: &[DNABase]) -> bool {
+        self.stop_codons.iter().any(|stop| &stop[..] == codon)
+    }
+}
+
+impl Translation<DNABase> for GeneticCode {
+    type Output = AminoAcid;
+
+    fn translate(&self, gene: &[DNABase]) -> BaseString<AminoAcid> {
+        let start = if self.require_start {
+            match gene.windows(3).position(|codon| self.is_start_codon(codon)) {
+                Some(start) => start,
+                None => return BaseString { v: Vec::new() },
+            }
+        } else {
+            0
+        };
+
+        BaseString {
+            v: gene[start..].chunks(3)
+                            .take_while(|codon| codon.len() == 3 && !self.is_stop_codon(codon))
+                            .filter_map(|codon| self.amino_acid(codon))
+                            .collect(),
+        }
+    }
+}
+
+#[test]
+fn test_amino_acid_chars() {
+    for amino_acid in AminoAcid::symbols() {
+        assert_eq!(Some(amino_acid), AminoAcid::from_char(amino_acid.to_char()));
+    }
+    assert_eq!('W', Trp.to_char());
+    assert_eq!(Ala, Val.succ());
+    assert_eq!(None, AminoAcid::from_char('*'));
+}
+
+#[test]
+fn test_standard_genetic_code() {
+    let code = GeneticCode::standard();
+    let gene: BaseString<DNABase> = "GG ATG GCC TGG TAA GGG".parse().unwrap();
+    let protein: BaseString<AminoAcid> = "MAW".parse().unwrap();
+    assert_eq!(protein, code.translate(&gene));
+
+    let code = GeneticCode { require_start: false, ..code };
+    assert_eq!("GWPGK", code.translate(&gene).to_string());
+
+    assert_eq!(3, code.stop_codons.len());
+    assert_eq!(Some(Phe), code.amino_acid(&[DNABase::T, DNABase::T, DNABase::T]));
+    assert!(code.is_stop_codon(&[DNABase::T, DNABase::G, DNABase::A]));
+}
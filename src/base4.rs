@@ -1,8 +1,9 @@
 use super::Base;
+use super::packed::PackedBase;
 use std::fmt;
 use rand::{Rand, Rng};
 
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
 pub struct Base4(u8);
 
 pub const B0: Base4 = Base4(0);
@@ -12,7 +13,7 @@ pub const B3: Base4 = Base4(3);
 
 impl Rand for Base4 {
     fn rand<R: Rng>(rng: &mut R) -> Self {
-        Base4::new(rng.gen_range(0, 4))
+        Base4::from_index(rng.gen_range(0, Base4::cardinality()))
     }
 }
 
@@ -36,8 +37,31 @@ impl Base for Base4 {
             _ => None,
         }
     }
+
+    fn to_char(self) -> char {
+        (b'0' + self.0) as char
+    }
+
+    // 0 pairs with 3, and 1 with 2.
+    fn complement(self) -> Self {
+        Base4(3 - self.0)
+    }
+
+    fn cardinality() -> usize {
+        4
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        Base4::new(index as u8)
+    }
 }
 
+impl PackedBase for Base4 {}
+
 impl Base4 {
     pub fn new(v: u8) -> Self {
         assert!(v < 4);
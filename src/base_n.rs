@@ -0,0 +1,113 @@
+// A generic alphabet of N symbols, written as the digits 0-9 followed by
+// the letters a-z.
+
+use super::Base;
+use std::fmt;
+use std::char;
+use rand::{Rand, Rng};
+
+// Digits and letters allow up to 36 symbols.
+const MAX_SYMBOLS: usize = 36;
+
+/// An alphabet of ```N``` symbols. Using it with ```N``` outside of
+/// ```2..=36``` fails to compile.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct BaseN<const N: usize>(u8);
+
+impl<const N: usize> BaseN<N> {
+    // Evaluated (and checked at compile time) by the constructors and
+    // ```cardinality```, so no value of an invalid alphabet can exist.
+    const VALID: () = assert!(N >= 2 && N <= MAX_SYMBOLS, "BaseN requires 2 <= N <= 36");
+
+    pub fn new(v: u8) -> Self {
+        let () = Self::VALID;
+        assert!((v as usize) < N);
+        BaseN(v)
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl<const N: usize> Rand for BaseN<N> {
+    fn rand<R: Rng>(rng: &mut R) -> Self {
+        BaseN::from_index(rng.gen_range(0, BaseN::<N>::cardinality()))
+    }
+}
+
+impl<const N: usize> fmt::Debug for BaseN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl<const N: usize> Base for BaseN<N> {
+    fn succ(self) -> Self {
+        BaseN(((self.0 as usize + 1) % N) as u8)
+    }
+
+    /// Letters are accepted in both cases.
+    fn from_char(c: char) -> Option<Self> {
+        let () = Self::VALID;
+        match c.to_digit(MAX_SYMBOLS as u32) {
+            Some(v) if (v as usize) < N => Some(BaseN(v as u8)),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        char::from_digit(self.0 as u32, MAX_SYMBOLS as u32).unwrap()
+    }
+
+    // Symbol i pairs with symbol N - 1 - i.
+    fn complement(self) -> Self {
+        BaseN((N - 1 - self.0 as usize) as u8)
+    }
+
+    fn cardinality() -> usize {
+        let () = Self::VALID;
+        N
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        let () = Self::VALID;
+        assert!(index < N);
+        BaseN(index as u8)
+    }
+}
+
+#[test]
+fn test_base_n() {
+    use super::Genome;
+
+    assert_eq!(BaseN::<2>::new(0), BaseN::<2>::new(1).succ());
+    assert_eq!(BaseN::<8>::new(5), BaseN::<8>::new(2).complement());
+    assert_eq!(None, BaseN::<8>::from_char('8'));
+    assert_eq!(Some(BaseN::<16>::new(10)), BaseN::<16>::from_char('A'));
+    assert_eq!('z', BaseN::<36>::new(35).to_char());
+
+    let genome: Genome<BaseN<16>> = "0123 4567 89ab cdef".parse().unwrap();
+    assert_eq!("0123456789abcdef", genome.to_string());
+    assert!("012".parse::<Genome<BaseN<2>>>().is_err());
+
+    let symbols: String = BaseN::<12>::symbols().map(|b| b.to_char()).collect();
+    assert_eq!("0123456789ab", symbols);
+}
+
+#[test]
+fn test_base_n_network() {
+    use super::{Genome, ProteinRegulator};
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<BaseN<2>>::random(&mut rng, 1000);
+    let promoter = [BaseN::new(0), BaseN::new(1), BaseN::new(1), BaseN::new(0), BaseN::new(1)];
+    let network = genome.construct_network(&promoter, 4, &|_| ProteinRegulator::enhance())
+                        .unwrap();
+    assert_eq!(genome.iter_genes(&promoter, 4).count(), network.nodes().len());
+}
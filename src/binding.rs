@@ -0,0 +1,89 @@
+// Models of how strongly a gene product binds to the regulatory region
+// of a gene. Used by `Genome::construct_network_with_binding`.
+
+use super::{Base, count_substr};
+
+/// The strength with which a gene product over alphabet ```P``` regulates a
+/// gene with a regulatory region over alphabet ```B```. A strength of 0.0
+/// means no regulation at all.
+pub trait Binding<P: Base, B: Base = P> {
+    fn strength(&self, product: &[P], regulatory_region: &[B]) -> f64;
+}
+
+/// The number of exact occurrences of the product in the regulatory region.
+/// This is the binding model of `Genome::construct_network`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExactBinding;
+
+impl<B: Base> Binding<B> for ExactBinding {
+    fn strength(&self, product: &[B], regulatory_region: &[B]) -> f64 {
+        count_substr(regulatory_region, product) as f64
+    }
+}
+
+/// How the scores of the windows of a regulatory region are combined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aggregate {
+    /// The score of the best matching window.
+    Best,
+    /// The sum of the scores of all windows.
+    Sum,
+}
+
+/// Scores the product against every window (of the same length) of the
+/// regulatory region with ```score```. Windows scoring below
+/// ```threshold``` do not bind. Product and regulatory region may use
+/// different alphabets.
+#[derive(Debug, Clone)]
+pub struct AffinityBinding<S> {
+    pub score: S,
+    pub aggregate: Aggregate,
+    pub threshold: f64,
+}
+
+impl<P, B, S> Binding<P, B> for AffinityBinding<S>
+    where P: Base,
+          B: Base,
+          S: Fn(&[P], &[B]) -> f64
+{
+    fn strength(&self, product: &[P], regulatory_region: &[B]) -> f64 {
+        assert!(!product.is_empty());
+        let scores = regulatory_region.windows(product.len())
+                                      .map(|window| (self.score)(product, window))
+                                      .filter(|&score| score >= self.threshold);
+        match self.aggregate {
+            Aggregate::Best => scores.fold(0.0, f64::max),
+            Aggregate::Sum => scores.sum(),
+        }
+    }
+}
+
+/// The fraction of positions in which ```a``` and ```b``` agree.
+pub fn hamming_affinity<B: Base>(a: &[B], b: &[B]) -> f64 {
+    assert!(a.len() == b.len() && !a.is_empty());
+    let matches = a.iter().zip(b.iter()).filter(|&(x, y)| x == y).count();
+    matches as f64 / a.len() as f64
+}
+
+#[test]
+fn test_affinity_binding() {
+    use super::dna_base::DNABase;
+    use super::BaseString;
+
+    let product: BaseString<DNABase> = "ATGC".parse().unwrap();
+    let region: BaseString<DNABase> = "ATGG ATGC".parse().unwrap();
+
+    assert_eq!(1.0, ExactBinding.strength(&product, &region));
+
+    let best = AffinityBinding {
+        score: hamming_affinity::<DNABase>,
+        aggregate: Aggregate::Best,
+        threshold: 0.75,
+    };
+    assert_eq!(1.0, best.strength(&product, &region));
+    assert_eq!(0.75, best.strength(&product, &region[..4]));
+    assert_eq!(0.0, best.strength(&product, &region[1..7]));
+
+    let sum = AffinityBinding { aggregate: Aggregate::Sum, ..best };
+    assert_eq!(1.75, sum.strength(&product, &region));
+}
@@ -0,0 +1,136 @@
+use super::{Base, Genome};
+use rand::Rng;
+use std::cmp;
+
+fn splice<B: Base>(head: &[B], tail: &[B]) -> Genome<B> {
+    let mut v = Vec::with_capacity(head.len() + tail.len());
+    v.extend_from_slice(head);
+    v.extend_from_slice(tail);
+    Genome::from_vec(v)
+}
+
+/// Exchanges the tails of both parents after a common, random cut point.
+pub fn one_point<B: Base, R: Rng>(rng: &mut R,
+                                  a: &Genome<B>,
+                                  b: &Genome<B>)
+                                  -> (Genome<B>, Genome<B>) {
+    let cut = rng.gen_range(0, cmp::min(a.len(), b.len()) + 1);
+    (splice(&a[..cut], &b[cut..]), splice(&b[..cut], &a[cut..]))
+}
+
+/// Exchanges the segment between two random cut points.
+pub fn two_point<B: Base, R: Rng>(rng: &mut R,
+                                  a: &Genome<B>,
+                                  b: &Genome<B>)
+                                  -> (Genome<B>, Genome<B>) {
+    let max = cmp::min(a.len(), b.len());
+    let c1 = rng.gen_range(0, max + 1);
+    let c2 = rng.gen_range(0, max + 1);
+    let (from, to) = (cmp::min(c1, c2), cmp::max(c1, c2));
+
+    let mut child_a = a[..].to_vec();
+    let mut child_b = b[..].to_vec();
+    child_a[from..to].copy_from_slice(&b[from..to]);
+    child_b[from..to].copy_from_slice(&a[from..to]);
+    (Genome::from_vec(child_a), Genome::from_vec(child_b))
+}
+
+/// Exchanges each base of the common prefix of both parents with
+/// probability 0.5. The longer parent's tail is kept as is.
+pub fn uniform<B: Base, R: Rng>(rng: &mut R,
+                                a: &Genome<B>,
+                                b: &Genome<B>)
+                                -> (Genome<B>, Genome<B>) {
+    let mut child_a = a[..].to_vec();
+    let mut child_b = b[..].to_vec();
+    for i in 0..cmp::min(a.len(), b.len()) {
+        if rng.gen() {
+            child_a[i] = b[i];
+            child_b[i] = a[i];
+        }
+    }
+    (Genome::from_vec(child_a), Genome::from_vec(child_b))
+}
+
+// The offsets right behind each gene (as found by ```iter_genes```).
+fn gene_boundaries<B: Base>(genome: &Genome<B>,
+                            promoter: &[B],
+                            length_of_gene: usize)
+                            -> Vec<usize> {
+    genome.iter_genes(promoter, length_of_gene)
+          .map(|gene| gene.location.gene.end)
+          .collect()
+}
+
+/// Crossover at gene boundaries. Both parents are cut right behind their
+/// i-th gene (for a random i) and the tails are exchanged, so that every
+/// gene is passed on together with its regulatory region.
+///
+/// Returns ```None``` if one of the parents has no genes.
+pub fn homologous<B: Base, R: Rng>(rng: &mut R,
+                                   a: &Genome<B>,
+                                   b: &Genome<B>,
+                                   promoter: &[B],
+                                   length_of_gene: usize)
+                                   -> Option<(Genome<B>, Genome<B>)> {
+    let boundaries_a = gene_boundaries(a, promoter, length_of_gene);
+    let boundaries_b = gene_boundaries(b, promoter, length_of_gene);
+
+    let num_genes = cmp::min(boundaries_a.len(), boundaries_b.len());
+    if num_genes == 0 {
+        return None;
+    }
+
+    let i = rng.gen_range(0, num_genes);
+    let (cut_a, cut_b) = (boundaries_a[i], boundaries_b[i]);
+    Some((splice(&a[..cut_a], &b[cut_b..]),
+          splice(&b[..cut_b], &a[cut_a..])))
+}
+
+#[test]
+fn test_one_point_preserves_bases() {
+    use super::base4::Base4;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let a = Genome::<Base4>::random(&mut rng, 50);
+    let b = Genome::<Base4>::random(&mut rng, 80);
+    for _ in 0..20 {
+        let (c, d) = one_point(&mut rng, &a, &b);
+        assert_eq!(a.len() + b.len(), c.len() + d.len());
+        let (c, d) = two_point(&mut rng, &a, &b);
+        assert_eq!((a.len(), b.len()), (c.len(), d.len()));
+        let (c, d) = uniform(&mut rng, &a, &b);
+        assert_eq!((a.len(), b.len()), (c.len(), d.len()));
+    }
+}
+
+#[test]
+fn test_homologous_exchanges_whole_genes() {
+    use super::base4::Base4;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let promoter: Genome<Base4> = "0101".parse().unwrap();
+    let a: Genome<Base4> = "22 0101 3333 2 0101 3232 2".parse().unwrap();
+    let b: Genome<Base4> = "0101 2222 33 0101 2323 0101 3322".parse().unwrap();
+
+    let genes = |g: &Genome<Base4>| -> Vec<Vec<Base4>> {
+        g.iter_genes(&promoter, 4).map(|gene| gene.gene.to_vec()).collect()
+    };
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    for _ in 0..10 {
+        let (c, d) = homologous(&mut rng, &a, &b, &promoter, 4).unwrap();
+        let mut parents = genes(&a);
+        parents.extend(genes(&b));
+        let mut children = genes(&c);
+        children.extend(genes(&d));
+        assert_eq!(parents.len(), children.len());
+        for gene in children.iter() {
+            assert!(parents.contains(gene));
+        }
+    }
+
+    let no_genes: Genome<Base4> = "2222".parse().unwrap();
+    assert!(homologous(&mut rng, &a, &no_genes, &promoter, 4).is_none());
+}
@@ -1,7 +1,8 @@
 use super::Base;
+use super::packed::PackedBase;
 use rand::{Rand, Rng};
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 #[repr(u8)]
 pub enum DNABase {
     A,
@@ -12,7 +13,7 @@ pub enum DNABase {
 
 impl Rand for DNABase {
     fn rand<R: Rng>(rng: &mut R) -> Self {
-        *rng.choose(&[DNABase::A, DNABase::T, DNABase::G, DNABase::C]).unwrap()
+        DNABase::from_index(rng.gen_range(0, DNABase::cardinality()))
     }
 }
 
@@ -35,9 +36,56 @@ impl Base for DNABase {
             _ => None,
         }
     }
+
+    fn to_char(self) -> char {
+        match self {
+            DNABase::A => 'A',
+            DNABase::T => 'T',
+            DNABase::G => 'G',
+            DNABase::C => 'C',
+        }
+    }
+
+    fn complement(self) -> Self {
+        match self {
+            DNABase::A => DNABase::T,
+            DNABase::T => DNABase::A,
+            DNABase::G => DNABase::C,
+            DNABase::C => DNABase::G,
+        }
+    }
+
+    fn cardinality() -> usize {
+        4
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => DNABase::A,
+            1 => DNABase::T,
+            2 => DNABase::G,
+            3 => DNABase::C,
+            _ => panic!("invalid index {}", index),
+        }
+    }
 }
 
+impl PackedBase for DNABase {}
+
 #[test]
 fn test_dnabase_succ() {
     assert_eq!(DNABase::A, DNABase::C.succ());
 }
+
+#[test]
+fn test_dnabase_complement() {
+    assert_eq!(DNABase::T, DNABase::A.complement());
+    assert_eq!(DNABase::C, DNABase::G.complement());
+    for b in DNABase::symbols() {
+        assert_eq!(b, b.complement().complement());
+    }
+}
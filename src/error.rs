@@ -0,0 +1,73 @@
+use std::error;
+use std::fmt;
+use std::io;
+use super::Strand;
+
+/// Reasons why a genome could not be parsed or developed.
+#[derive(Debug)]
+pub enum Error {
+    /// The genome does not contain the promoter.
+    NoPromoterFound,
+    /// The promoter at ```position``` is not followed by a complete gene.
+    /// Positions on the reverse strand refer to the reverse complement.
+    TruncatedGene { position: usize, strand: Strand },
+    /// The promoter at ```position``` is not followed by a terminator within
+    /// the length bounds of the gene model.
+    NoTerminator { position: usize, strand: Strand },
+    /// The character at (character) ```position``` is not a valid base.
+    InvalidCharacter { character: char, position: usize },
+    /// Development produced more than ```limit``` edges.
+    DevelopmentLimitExceeded { limit: usize },
+    /// The developed graph has no processing nodes.
+    NoProcessingNodes,
+    /// Line ```line``` of a FASTA file is malformed at character
+    /// ```column``` (both starting at 1).
+    MalformedFasta { line: usize, column: usize },
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NoPromoterFound => write!(f, "no promoter found"),
+            Error::TruncatedGene { position, strand } => {
+                write!(f,
+                       "gene of promoter at position {} ({} strand) is truncated",
+                       position,
+                       strand)
+            }
+            Error::NoTerminator { position, strand } => {
+                write!(f,
+                       "no terminator within bounds behind promoter at position {} ({} strand)",
+                       position,
+                       strand)
+            }
+            Error::InvalidCharacter { character, position } => {
+                write!(f, "invalid character {:?} at position {}", character, position)
+            }
+            Error::DevelopmentLimitExceeded { limit } => {
+                write!(f, "development exceeded the limit of {} edges", limit)
+            }
+            Error::NoProcessingNodes => write!(f, "no processing nodes"),
+            Error::MalformedFasta { line, column } => {
+                write!(f, "malformed FASTA in line {} column {}", line, column)
+            }
+            Error::Io(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
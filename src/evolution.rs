@@ -0,0 +1,413 @@
+use super::{Base, Genome, Error};
+use super::base4::Base4;
+use super::graph::{Graph, graph_from_base4_genome};
+use super::mutation::MutationRates;
+use super::crossover;
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// Evaluates a developed graph. Higher values are better.
+pub trait Fitness {
+    fn fitness(&self, graph: &Graph) -> f64;
+}
+
+impl<F> Fitness for F
+    where F: Fn(&Graph) -> f64
+{
+    fn fitness(&self, graph: &Graph) -> f64 {
+        self(graph)
+    }
+}
+
+/// Develops a genome into a graph. Fails for non-viable genomes.
+pub trait Development<B: Base> {
+    fn develop(&self, genome: &Genome<B>) -> Result<Graph, Error>;
+}
+
+/// Development using `graph_from_base4_genome`.
+#[derive(Debug, Clone)]
+pub struct Base4Development {
+    pub num_iterations: usize,
+}
+
+impl Development<Base4> for Base4Development {
+    fn develop(&self, genome: &Genome<Base4>) -> Result<Graph, Error> {
+        graph_from_base4_genome(genome, self.num_iterations)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Selection {
+    /// The best of ```k``` uniformly drawn individuals.
+    Tournament(usize),
+    /// Uniformly drawn from the given fraction of the best individuals.
+    Truncation(f64),
+    /// Fitness proportionate selection. Negative and missing fitness values
+    /// are treated as zero.
+    Roulette,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Crossover {
+    /// See ```crossover::one_point```.
+    OnePoint,
+    /// See ```crossover::two_point```.
+    TwoPoint,
+    /// See ```crossover::uniform```.
+    Uniform,
+}
+
+impl Crossover {
+    fn apply<B: Base, R: Rng>(self,
+                              rng: &mut R,
+                              a: &Genome<B>,
+                              b: &Genome<B>)
+                              -> (Genome<B>, Genome<B>) {
+        match self {
+            Crossover::OnePoint => crossover::one_point(rng, a, b),
+            Crossover::TwoPoint => crossover::two_point(rng, a, b),
+            Crossover::Uniform => crossover::uniform(rng, a, b),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EvolutionConfig {
+    pub selection: Selection,
+    pub crossover: Crossover,
+    // Number of best individuals that are copied unmodified into the next generation.
+    pub elitism: usize,
+    // Probability that an offspring is produced by crossover instead of cloning.
+    pub crossover_rate: f64,
+    pub mutation: MutationRates,
+}
+
+#[derive(Debug, Clone)]
+pub struct Individual<B: Base> {
+    pub genome: Genome<B>,
+    // ```None``` if the genome was not yet evaluated or is non-viable.
+    pub fitness: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerationStats {
+    pub generation: usize,
+    pub num_viable: usize,
+    // All three are ```None``` if no individual is viable.
+    pub best: Option<f64>,
+    pub mean: Option<f64>,
+    pub worst: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Population<B: Base> {
+    individuals: Vec<Individual<B>>,
+    generation: usize,
+}
+
+// Orders by fitness, non-viable individuals being the worst.
+fn cmp_fitness(a: Option<f64>, b: Option<f64>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl<B: Base> Population<B> {
+    pub fn new(genomes: Vec<Genome<B>>) -> Population<B> {
+        assert!(!genomes.is_empty());
+        Population {
+            individuals: genomes.into_iter()
+                                .map(|genome| {
+                                    Individual {
+                                        genome,
+                                        fitness: None,
+                                    }
+                                })
+                                .collect(),
+            generation: 0,
+        }
+    }
+
+    pub fn random<R: Rng>(rng: &mut R, size: usize, genome_length: usize) -> Population<B> {
+        Population::new((0..size).map(|_| Genome::random(rng, genome_length)).collect())
+    }
+
+    pub fn individuals(&self) -> &[Individual<B>] {
+        &self.individuals
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn best(&self) -> Option<&Individual<B>> {
+        self.individuals
+            .iter()
+            .filter(|ind| ind.fitness.is_some())
+            .max_by(|a, b| cmp_fitness(a.fitness, b.fitness))
+    }
+
+    /// Develops and evaluates every individual.
+    pub fn evaluate<D, F>(&mut self, development: &D, fitness: &F)
+        where D: Development<B>,
+              F: Fitness
+    {
+        for ind in self.individuals.iter_mut() {
+            ind.fitness = development.develop(&ind.genome)
+                                     .ok()
+                                     .map(|graph| fitness.fitness(&graph));
+        }
+    }
+
+    pub fn stats(&self) -> GenerationStats {
+        let values: Vec<f64> = self.individuals.iter().filter_map(|ind| ind.fitness).collect();
+        let num_viable = values.len();
+        let (best, mean, worst) = if values.is_empty() {
+            (None, None, None)
+        } else {
+            (Some(values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)),
+             Some(values.iter().sum::<f64>() / num_viable as f64),
+             Some(values.iter().cloned().fold(f64::INFINITY, f64::min)))
+        };
+        GenerationStats {
+            generation: self.generation,
+            num_viable,
+            best,
+            mean,
+            worst,
+        }
+    }
+
+    // Indices of the individuals, best first.
+    fn ranking(&self) -> Vec<usize> {
+        let mut ranking: Vec<usize> = (0..self.individuals.len()).collect();
+        ranking.sort_by(|&a, &b| {
+            cmp_fitness(self.individuals[b].fitness, self.individuals[a].fitness)
+        });
+        ranking
+    }
+
+    fn select<R: Rng>(&self, rng: &mut R, selection: Selection, ranking: &[usize]) -> usize {
+        let n = self.individuals.len();
+        match selection {
+            Selection::Tournament(k) => {
+                assert!(k > 0);
+                let mut best = rng.gen_range(0, n);
+                for _ in 1..k {
+                    let other = rng.gen_range(0, n);
+                    if cmp_fitness(self.individuals[other].fitness,
+                                   self.individuals[best].fitness) == Ordering::Greater {
+                        best = other;
+                    }
+                }
+                best
+            }
+            Selection::Truncation(fraction) => {
+                assert!(fraction > 0.0 && fraction <= 1.0);
+                let m = ((n as f64 * fraction).ceil() as usize).max(1);
+                ranking[rng.gen_range(0, m)]
+            }
+            Selection::Roulette => {
+                let weight = |ind: &Individual<B>| ind.fitness.unwrap_or(0.0).max(0.0);
+                let total: f64 = self.individuals.iter().map(&weight).sum();
+                if total <= 0.0 {
+                    return rng.gen_range(0, n);
+                }
+                let mut r = rng.gen::<f64>() * total;
+                for (i, ind) in self.individuals.iter().enumerate() {
+                    r -= weight(ind);
+                    if r < 0.0 {
+                        return i;
+                    }
+                }
+                ranking[0]
+            }
+        }
+    }
+
+    /// Replaces the (evaluated) population by its offspring. The fitness
+    /// of the new individuals is reset.
+    pub fn next_generation<R: Rng>(&mut self, rng: &mut R, config: &EvolutionConfig) {
+        let n = self.individuals.len();
+        let ranking = self.ranking();
+
+        let mut offspring: Vec<Genome<B>> = ranking.iter()
+                                                   .take(config.elitism)
+                                                   .map(|&i| self.individuals[i].genome.clone())
+                                                   .collect();
+
+        while offspring.len() < n {
+            let a = self.select(rng, config.selection, &ranking);
+            if rng.gen::<f64>() < config.crossover_rate {
+                let b = self.select(rng, config.selection, &ranking);
+                let (mut c, mut d) = config.crossover.apply(rng,
+                                                            &self.individuals[a].genome,
+                                                            &self.individuals[b].genome);
+                c.mutate(rng, &config.mutation);
+                offspring.push(c);
+                if offspring.len() < n {
+                    d.mutate(rng, &config.mutation);
+                    offspring.push(d);
+                }
+            } else {
+                let mut c = self.individuals[a].genome.clone();
+                c.mutate(rng, &config.mutation);
+                offspring.push(c);
+            }
+        }
+
+        let generation = self.generation + 1;
+        *self = Population::new(offspring);
+        self.generation = generation;
+    }
+
+    /// Evaluates and breeds the population for ```num_generations``` generations,
+    /// calling ```callback``` after each evaluation. The population is left
+    /// evaluated.
+    pub fn run<R, D, F, C>(&mut self,
+                           rng: &mut R,
+                           config: &EvolutionConfig,
+                           development: &D,
+                           fitness: &F,
+                           num_generations: usize,
+                           mut callback: C)
+        where R: Rng,
+              D: Development<B>,
+              F: Fitness,
+              C: FnMut(&GenerationStats, &Population<B>)
+    {
+        for i in 0..num_generations {
+            if i > 0 {
+                self.next_generation(rng, config);
+            }
+            self.evaluate(development, fitness);
+            callback(&self.stats(), self);
+        }
+    }
+}
+
+#[test]
+fn test_run_with_elitism() {
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut population = Population::<Base4>::random(&mut rng, 10, 8 * 64);
+    let config = EvolutionConfig {
+        selection: Selection::Tournament(3),
+        crossover: Crossover::OnePoint,
+        elitism: 1,
+        crossover_rate: 0.5,
+        mutation: MutationRates::default(),
+    };
+    let development = Base4Development { num_iterations: 4 };
+    let fitness = |graph: &Graph| graph.nodes.len() as f64;
+
+    let mut history: Vec<GenerationStats> = Vec::new();
+    population.run(&mut rng,
+                   &config,
+                   &development,
+                   &fitness,
+                   4,
+                   |stats, _| history.push(stats.clone()));
+
+    assert_eq!(4, history.len());
+    assert_eq!(3, population.generation());
+    for (i, w) in history.windows(2).enumerate() {
+        assert_eq!(i, w[0].generation);
+        assert!(cmp_fitness(w[1].best, w[0].best) != Ordering::Less);
+    }
+}
+
+#[test]
+fn test_truncation_and_roulette_selection() {
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut population = Population::<Base4>::random(&mut rng, 4, 8);
+    let fitness = [Some(3.0), None, Some(1.0), Some(0.0)];
+    for (ind, &f) in population.individuals.iter_mut().zip(fitness.iter()) {
+        ind.fitness = f;
+    }
+    let ranking = population.ranking();
+    assert_eq!(vec![0, 2, 3, 1], ranking);
+
+    // Only the best individual is within the truncated quarter.
+    for _ in 0..20 {
+        assert_eq!(0, population.select(&mut rng, Selection::Truncation(0.25), &ranking));
+    }
+
+    // Non-viable individuals and those with zero fitness are never drawn.
+    let mut counts = [0; 4];
+    for _ in 0..400 {
+        counts[population.select(&mut rng, Selection::Roulette, &ranking)] += 1;
+    }
+    assert_eq!(0, counts[1]);
+    assert_eq!(0, counts[3]);
+    assert!(counts[0] > counts[2]);
+    assert!(counts[2] > 0);
+}
+
+#[test]
+fn test_stats_with_non_viable_individuals() {
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut population = Population::<Base4>::random(&mut rng, 4, 8);
+
+    let stats = population.stats();
+    assert_eq!(0, stats.num_viable);
+    assert!(stats.best.is_none() && stats.mean.is_none() && stats.worst.is_none());
+    assert!(population.best().is_none());
+
+    let fitness = [None, Some(4.0), None, Some(2.0)];
+    for (ind, &f) in population.individuals.iter_mut().zip(fitness.iter()) {
+        ind.fitness = f;
+    }
+    let stats = population.stats();
+    assert_eq!(2, stats.num_viable);
+    assert_eq!(Some(4.0), stats.best);
+    assert_eq!(Some(3.0), stats.mean);
+    assert_eq!(Some(2.0), stats.worst);
+    assert_eq!(Some(4.0), population.best().unwrap().fitness);
+}
+
+#[test]
+fn test_next_generation_with_crossover() {
+    use rand::{SeedableRng, XorShiftRng};
+
+    for &crossover in &[Crossover::OnePoint, Crossover::TwoPoint, Crossover::Uniform] {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let a = Genome::from_vec(vec![Base4::new(0); 16]);
+        let b = Genome::from_vec(vec![Base4::new(3); 16]);
+        let mut population = Population::new(vec![a, b]);
+        for ind in population.individuals.iter_mut() {
+            ind.fitness = Some(1.0);
+        }
+        let config = EvolutionConfig {
+            selection: Selection::Roulette,
+            crossover,
+            elitism: 0,
+            crossover_rate: 1.0,
+            mutation: MutationRates {
+                point: 0.0,
+                insertion: 0.0,
+                deletion: 0.0,
+                duplication: 0.0,
+                inversion: 0.0,
+                transposition: 0.0,
+                max_segment_length: 1,
+            },
+        };
+        population.next_generation(&mut rng, &config);
+
+        assert_eq!(1, population.generation());
+        assert_eq!(2, population.individuals().len());
+        for ind in population.individuals() {
+            assert_eq!(16, ind.genome.len());
+            assert!(ind.fitness.is_none());
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Reading and writing genomes in FASTA format.
+//
+// The header line of a record carries the name of the genome, followed by
+// optional whitespace separated key=value pairs, e.g.
+//
+//     >genome42 generation=17 fitness=0.75
+//
+// All other words of the header form a free-text description, as in
+// ordinary FASTA files:
+//
+//     >chr1 Homo sapiens
+
+use super::{Base, Genome, Error};
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastaRecord<B: Base> {
+    pub name: String,
+    // Header words which are not key=value pairs, separated by a space.
+    pub description: String,
+    pub metadata: Vec<(String, String)>,
+    pub genome: Genome<B>,
+}
+
+impl<B: Base> FastaRecord<B> {
+    pub fn new(name: &str, genome: Genome<B>) -> FastaRecord<B> {
+        FastaRecord {
+            name: name.to_string(),
+            description: String::new(),
+            metadata: Vec::new(),
+            genome,
+        }
+    }
+
+    /// The value of the first metadata entry with ```key```.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find(|&(k, _)| k == key).map(|(_, v)| &v[..])
+    }
+
+    /// Adds a metadata entry. Neither ```key``` nor ```value``` may contain
+    /// whitespace, and ```key``` may not contain '='.
+    pub fn set<V: ToString>(&mut self, key: &str, value: V) {
+        let value = value.to_string();
+        assert!(!key.is_empty() && !key.contains('=') && !key.contains(char::is_whitespace));
+        assert!(!value.contains(char::is_whitespace));
+        self.metadata.push((key.to_string(), value));
+    }
+}
+
+// Whether ```description``` is read back unchanged: words separated by single
+// spaces, none of which looks like a key=value pair.
+fn is_valid_description(description: &str) -> bool {
+    description.split(' ').all(|word| {
+        !word.is_empty() && !word.contains(char::is_whitespace) &&
+        word.find('=').is_none_or(|eq| eq == 0)
+    })
+}
+
+// Parses a sequence line, accepting lowercase letters for uppercase bases.
+// Returns the bases or the (0-based) column of the first invalid character.
+fn parse_sequence<B: Base>(line: &str) -> Result<Vec<B>, usize> {
+    let mut bases = Vec::with_capacity(line.len());
+    for (column, c) in line.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+        match B::from_char(c).or_else(|| B::from_char(c.to_ascii_uppercase())) {
+            Some(base) => bases.push(base),
+            None => return Err(column),
+        }
+    }
+    Ok(bases)
+}
+
+// The name, description and metadata of a record.
+type Header = (String, String, Vec<(String, String)>);
+
+// Parses the header line (without the leading '>'). Fails only without name.
+fn parse_header(header: &str) -> Option<Header> {
+    let mut words = header.split_whitespace();
+    let name = words.next()?;
+    let mut description: Vec<&str> = Vec::new();
+    let mut metadata = Vec::new();
+    for word in words {
+        match word.find('=') {
+            Some(eq) if eq > 0 => {
+                metadata.push((word[..eq].to_string(), word[eq + 1..].to_string()))
+            }
+            _ => description.push(word),
+        }
+    }
+    Some((name.to_string(), description.join(" "), metadata))
+}
+
+/// Reads all records of a FASTA file. Empty lines and comment lines
+/// starting with ';' are ignored. Bases may be written in lowercase.
+pub fn read_fasta<B: Base, R: BufRead>(rd: R) -> Result<Vec<FastaRecord<B>>, Error> {
+    let mut records = Vec::new();
+    let mut current: Option<Header> = None;
+    let mut bases: Vec<B> = Vec::new();
+
+    for (i, line) in rd.lines().enumerate() {
+        let raw = line?;
+        let line = raw.trim();
+        let line_no = i + 1;
+        // column of the first character of ```line```
+        let first_column = raw.chars().take_while(|c| c.is_whitespace()).count() + 1;
+        let malformed = |offset: usize| {
+            Error::MalformedFasta {
+                line: line_no,
+                column: first_column + offset,
+            }
+        };
+
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('>') {
+            if let Some((name, description, metadata)) = current.take() {
+                records.push(FastaRecord {
+                    name,
+                    description,
+                    metadata,
+                    genome: Genome::from_vec(bases),
+                });
+                bases = Vec::new();
+            }
+            current = Some(parse_header(header).ok_or_else(|| malformed(1))?);
+        } else {
+            if current.is_none() {
+                return Err(malformed(0));
+            }
+            bases.extend(parse_sequence::<B>(line).map_err(&malformed)?);
+        }
+    }
+
+    if let Some((name, description, metadata)) = current {
+        records.push(FastaRecord {
+            name,
+            description,
+            metadata,
+            genome: Genome::from_vec(bases),
+        });
+    }
+
+    Ok(records)
+}
+
+/// Writes ```records``` with at most ```line_width``` bases per line. With a
+/// ```line_width``` of 0, each genome is written on a single line.
+///
+/// Fails with ```InvalidInput``` if a description would not be read back
+/// unchanged, i.e. contains a key=value word or other whitespace than single
+/// spaces between words.
+pub fn write_fasta<B: Base, W: Write>(wr: &mut W,
+                                      records: &[FastaRecord<B>],
+                                      line_width: usize)
+                                      -> io::Result<()> {
+    for record in records.iter() {
+        if !record.description.is_empty() && !is_valid_description(&record.description) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      format!("invalid description {:?}", record.description)));
+        }
+        write!(wr, ">{}", record.name)?;
+        if !record.description.is_empty() {
+            write!(wr, " {}", record.description)?;
+        }
+        for (key, value) in record.metadata.iter() {
+            write!(wr, " {}={}", key, value)?;
+        }
+        writeln!(wr)?;
+        if record.genome.is_empty() {
+            continue;
+        }
+        if line_width == 0 {
+            writeln!(wr, "{}", record.genome)?;
+        } else {
+            writeln!(wr, "{}", record.genome.wrapped(line_width))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fasta_round_trip() {
+    use super::dna_base::DNABase;
+
+    let mut a = FastaRecord::new("a", "ATGCATGCAT".parse::<Genome<DNABase>>().unwrap());
+    a.set("generation", 17);
+    a.set("fitness", 0.75);
+    let mut b = FastaRecord::new("b", "GGGG".parse::<Genome<DNABase>>().unwrap());
+    b.description = "Homo sapiens".to_string();
+
+    let mut out = Vec::new();
+    write_fasta(&mut out, &[a.clone(), b.clone()], 4).unwrap();
+    assert_eq!(">a generation=17 fitness=0.75\nATGC\nATGC\nAT\n>b Homo sapiens\nGGGG\n",
+               String::from_utf8(out.clone()).unwrap());
+
+    let mut unwrapped = Vec::new();
+    write_fasta(&mut unwrapped, &[a.clone()], 0).unwrap();
+    assert_eq!(">a generation=17 fitness=0.75\nATGCATGCAT\n",
+               String::from_utf8(unwrapped).unwrap());
+
+    let records: Vec<FastaRecord<DNABase>> = read_fasta(&out[..]).unwrap();
+    assert_eq!(vec![a, b], records);
+    assert_eq!(Some("17"), records[0].get("generation"));
+    assert_eq!(None, records[1].get("generation"));
+
+    // descriptions which would be read as metadata are rejected.
+    for description in ["a=b c", "a  b", "a\nb"].iter() {
+        let mut c = records[1].clone();
+        c.description = description.to_string();
+        let err = write_fasta(&mut Vec::new(), &[c], 4).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+    }
+
+    let records: Vec<FastaRecord<DNABase>> = read_fasta(">a\natgc\nATgc\n".as_bytes()).unwrap();
+    assert_eq!("ATGCATGC", records[0].genome.to_string());
+}
+
+#[test]
+fn test_read_fasta_errors() {
+    use super::base4::Base4;
+
+    let input = ";comment\n>x\n0123\n\n>y\n 01 x3\n";
+    match read_fasta::<Base4, _>(input.as_bytes()) {
+        Err(Error::MalformedFasta { line: 6, column: 5 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    match read_fasta::<Base4, _>("0123\n".as_bytes()) {
+        Err(Error::MalformedFasta { line: 1, column: 1 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    match read_fasta::<Base4, _>(">\n0123\n".as_bytes()) {
+        Err(Error::MalformedFasta { line: 1, column: 2 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // words which are not key=value pairs form the description.
+    let records = read_fasta::<Base4, _>(">chr1 Homo k=v sapiens =x\n0123\n".as_bytes()).unwrap();
+    assert_eq!("Homo sapiens =x", records[0].description);
+    assert_eq!(Some("v"), records[0].get("k"));
+}
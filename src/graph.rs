@@ -1,4 +1,4 @@
-use super::{Genome, ProteinRegulator, GeneNetwork, GeneNetworkState};
+use super::{Genome, ProteinRegulator, GeneNetwork, GeneNetworkState, Error};
 use super::base4::{Base4, B0, B1};
 use std::mem;
 use std::io::{self, Write};
@@ -30,6 +30,10 @@ const EDGE_TYPE: usize = 6;
 
 const RESIZE_FACTOR: f32 = 0.25;
 
+/// Default limit of `graph_from_base4_genome`: development is aborted with
+/// `Error::DevelopmentLimitExceeded` once the number of edges exceeds it.
+pub const MAX_EDGES: usize = 1 << 20;
+
 impl Edge {
     fn transition_state(&mut self, network: &GeneNetwork) {
         // create an empty new state.
@@ -37,7 +41,7 @@ impl Edge {
 
         for (i, node) in network.nodes().iter().enumerate() {
             // determine the new state of ```node``` (position i in new_state)
-            if node.sum_edges(&self.network_state) > 0 {
+            if node.sum_edges(&self.network_state) > 0.0 {
                 // node is enabled
                 new_state.state.insert(i);
             }
@@ -357,10 +361,11 @@ struct GraphBuilder {
     edges: Vec<Edge>,
     next_node_id: usize,
     network: GeneNetwork,
+    max_edges: usize,
 }
 
 impl GraphBuilder {
-    fn new(network: GeneNetwork, zygote: GeneNetworkState) -> GraphBuilder {
+    fn new(network: GeneNetwork, zygote: GeneNetworkState, max_edges: usize) -> GraphBuilder {
         let initial_edge = Edge {
             src_node: 0,
             dst_node: 1,
@@ -373,11 +378,12 @@ impl GraphBuilder {
             edges: vec![initial_edge],
             next_node_id: 2,
             network: network,
+            max_edges,
         }
     }
 
     // During the process, some edges will be added (split), some others will be modified.
-    fn next(&mut self) {
+    fn next(&mut self) -> Result<(), Error> {
         let mut new_edges = Vec::new();
         for edge in self.edges.iter_mut() {
             edge.develop(&self.network, &mut self.next_node_id, &mut new_edges);
@@ -385,6 +391,11 @@ impl GraphBuilder {
         // println!("next_node_id: {}", self.next_node_id);
         // println!("new edges: {:?}", new_edges);
         self.edges.extend(new_edges);
+
+        if self.edges.len() > self.max_edges {
+            return Err(Error::DevelopmentLimitExceeded { limit: self.max_edges });
+        }
+        Ok(())
     }
 
     // The result of the GraphBuilder is a graph where every edge represents an element (either a
@@ -464,7 +475,18 @@ impl GraphBuilder {
     }
 }
 
-pub fn graph_from_base4_genome(genome: &Genome<Base4>, num_iterations: usize) -> Option<Graph> {
+pub fn graph_from_base4_genome(genome: &Genome<Base4>,
+                               num_iterations: usize)
+                               -> Result<Graph, Error> {
+    graph_from_base4_genome_with_limit(genome, num_iterations, MAX_EDGES)
+}
+
+/// Like `graph_from_base4_genome`, but development is aborted once the
+/// number of edges exceeds ```max_edges```.
+pub fn graph_from_base4_genome_with_limit(genome: &Genome<Base4>,
+                                          num_iterations: usize,
+                                          max_edges: usize)
+                                          -> Result<Graph, Error> {
     // use std::fs::File;
     let promoter = [B0, B1, B0, B1];
 
@@ -481,14 +503,7 @@ pub fn graph_from_base4_genome(genome: &Genome<Base4>, num_iterations: usize) ->
                                                    // Inhibitor
                                                    ProteinRegulator::enhance()
                                                }
-                                           });
-
-    let network = if let Some(n) = network {
-        n
-    } else {
-        return None;
-    };
-
+                                           })?;
 
     // println!("{:#?}", network);
 
@@ -496,11 +511,11 @@ pub fn graph_from_base4_genome(genome: &Genome<Base4>, num_iterations: usize) ->
     zygote.state.set(0, true);
     // zygote.state.set(1, true);
 
-    let mut gb = GraphBuilder::new(network, zygote);
+    let mut gb = GraphBuilder::new(network, zygote, max_edges);
     // println!("{:#?}", gb);
 
     for _ in 0..num_iterations {
-        gb.next();
+        gb.next()?;
     }
     // println!("{:#?}", gb);
 
@@ -512,5 +527,9 @@ pub fn graph_from_base4_genome(genome: &Genome<Base4>, num_iterations: usize) ->
     // println!("g: {:?}", g);
     // g.write_dot(&mut File::create("example1_struct.dot").unwrap()).unwrap();
 
-    Some(g.into_graph())
+    if g.nodes.is_empty() {
+        return Err(Error::NoProcessingNodes);
+    }
+
+    Ok(g.into_graph())
 }
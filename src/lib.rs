@@ -2,24 +2,96 @@ extern crate fixedbitset;
 extern crate rand;
 
 pub mod dna_base;
+pub mod rna_base;
 pub mod base4;
+pub mod base_n;
 pub mod graph;
+pub mod mutation;
+pub mod crossover;
+pub mod evolution;
+pub mod nsga2;
+pub mod novelty;
+pub mod error;
+pub mod fasta;
+pub mod packed;
+pub mod search;
+pub mod binding;
+pub mod translation;
+pub mod amino_acid;
+pub mod stats;
 
 use std::str::FromStr;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::{cmp, vec};
 use fixedbitset::FixedBitSet;
 use rand::{Rng, Rand};
 
+pub use error::Error;
+use search::PatternIndex;
+use binding::Binding;
+use translation::{Translation, Successor};
+
 /// Represents the bases used in the genome string.
 /// For example the bases of the DNA are adenine (A),
 /// thymine (T), guanine (G) and cytosine (C).
-pub trait Base: Sized + PartialEq + Eq + Copy + Clone + Debug + Rand {
+pub trait Base: Sized + PartialEq + Eq + Hash + Copy + Clone + Debug + Rand {
     /// Returns the "successor" base, wrapping around. Used
     /// to produce the gene product.
     fn succ(self) -> Self;
 
     fn from_char(c: char) -> Option<Self>;
+
+    /// The inverse of `from_char`.
+    fn to_char(self) -> char;
+
+    /// The base pairing with this one on the opposite strand. Applying it
+    /// twice yields the original base.
+    fn complement(self) -> Self;
+
+    /// The number of symbols of the alphabet.
+    fn cardinality() -> usize;
+
+    /// The position of this base in the alphabet, in ```0..cardinality()```.
+    fn index(self) -> usize;
+
+    /// The inverse of `index`. Panics if ```index >= cardinality()```.
+    fn from_index(index: usize) -> Self;
+
+    /// All symbols of the alphabet in the order of their index.
+    fn symbols() -> Symbols<Self> {
+        Symbols {
+            index: 0,
+            _base: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the symbols of an alphabet. See `Base::symbols`.
+pub struct Symbols<B: Base> {
+    index: usize,
+    _base: PhantomData<B>,
+}
+
+impl<B: Base> Iterator for Symbols<B> {
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.index < B::cardinality() {
+            self.index += 1;
+            Some(B::from_index(self.index - 1))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = B::cardinality() - self.index;
+        (n, Some(n))
+    }
 }
 
 // Locate ```substr``` in ```s```.
@@ -53,85 +125,449 @@ fn count_substr<T: Eq>(s: &[T], substr: &[T]) -> usize {
     return cnt;
 }
 
+// Locate the first window of ```s``` which differs from ```pattern``` in at most
+// ```max_mismatches``` positions. Returns the position and the number of mismatches.
+fn locate_fuzzy<T: Eq>(s: &[T], pattern: &[T], max_mismatches: usize) -> Option<(usize, usize)> {
+    if max_mismatches == 0 {
+        return locate_substr(s, pattern).map(|pos| (pos, 0));
+    }
+    assert!(!pattern.is_empty());
+
+    for (i, window) in s.windows(pattern.len()).enumerate() {
+        let mut mismatches = 0;
+        for (a, b) in window.iter().zip(pattern.iter()) {
+            if a != b {
+                mismatches += 1;
+                if mismatches > max_mismatches {
+                    break;
+                }
+            }
+        }
+        if mismatches <= max_mismatches {
+            return Some((i, mismatches));
+        }
+    }
+
+    None
+}
+
+// Number of mismatches between ```pattern``` and the bases of the circular
+// sequence ```s``` starting at ```start```, or ```None``` if it exceeds
+// ```max_mismatches```.
+fn mismatches_circular<T: Eq>(s: &[T],
+                              start: usize,
+                              pattern: &[T],
+                              max_mismatches: usize)
+                              -> Option<usize> {
+    let mut mismatches = 0;
+    for (k, b) in pattern.iter().enumerate() {
+        if s[(start + k) % s.len()] != *b {
+            mismatches += 1;
+            if mismatches > max_mismatches {
+                return None;
+            }
+        }
+    }
+    Some(mismatches)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    Linear,
+    // The last base is followed by the first one, as in bacterial chromosomes.
+    // Genes and regulatory regions may span the end of the sequence.
+    Circular,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlap {
+    // Scanning resumes behind the end of each gene.
+    Disjoint,
+    // Scanning resumes right behind each promoter, so that a promoter inside
+    // the body of a gene starts another gene. Regulatory regions still end
+    // at the previous promoter.
+    Genes,
+    // Like ```Genes```, but regulatory regions are only bounded by
+    // ```max_regulatory_length``` and may contain the promoters (and genes)
+    // of other genes.
+    GenesAndRegulatoryRegions,
+}
+
+/// The strand of a genome a gene is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strand {
+    Forward,
+    // The reverse complement of the genome.
+    Reverse,
+}
+
+impl fmt::Display for Strand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Strand::Forward => write!(f, "forward"),
+            Strand::Reverse => write!(f, "reverse"),
+        }
+    }
+}
+
+/// Determines where a gene ends.
+#[derive(Debug, Clone)]
+pub enum GeneModel<'b, B: Base + 'b> {
+    // Every gene has this number of bases.
+    FixedLength(usize),
+    // A gene runs from its promoter up to the first occurrence of
+    // ```terminator```. Genes with less than ```min_length``` or more than
+    // ```max_length``` bases (excluding the terminator) are not read.
+    Terminated {
+        terminator: &'b [B],
+        min_length: usize,
+        max_length: usize,
+    },
+}
+
+impl<'b, B: Base + 'b> GeneModel<'b, B> {
+    // Smallest number of bases from the start of a gene to its end (including
+    // the terminator).
+    fn min_extent(&self) -> usize {
+        match *self {
+            GeneModel::FixedLength(length) => length,
+            GeneModel::Terminated { terminator, min_length, .. } => min_length + terminator.len(),
+        }
+    }
+
+    // End of the gene starting at ```gene_start``` and end of its terminator,
+    // neither exceeding ```limit```. Positions beyond the end of ```s``` wrap
+    // around.
+    fn locate_end(&self, s: &[B], gene_start: usize, limit: usize) -> Option<(usize, usize)> {
+        match *self {
+            GeneModel::FixedLength(length) => {
+                let gene_end = gene_start + length;
+                if gene_end > limit {
+                    None
+                } else {
+                    Some((gene_end, gene_end))
+                }
+            }
+            GeneModel::Terminated { terminator, min_length, max_length } => {
+                assert!(!terminator.is_empty());
+                for length in 0..=max_length {
+                    let gene_end = gene_start + length;
+                    if gene_end + terminator.len() > limit {
+                        break;
+                    }
+                    if mismatches_circular(s, gene_end % s.len(), terminator, 0).is_some() {
+                        if length < min_length {
+                            break;
+                        }
+                        return Some((gene_end, gene_end + terminator.len()));
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Describes how genes are read from a genome.
+#[derive(Debug, Clone)]
+pub struct GeneReading<'b, B: Base + 'b> {
+    pub promoter: &'b [B],
+    pub gene_model: GeneModel<'b, B>,
+    // A promoter is recognised if it differs in at most this number of bases
+    // (Hamming distance).
+    pub max_mismatches: usize,
+    // The regulatory region consists of at most this number of bases
+    // immediately upstream of the promoter.
+    pub max_regulatory_length: Option<usize>,
+    // Promoters starting less than this number of bases behind the end of
+    // the previous gene (or promoter, if genes overlap) are ignored.
+    pub min_spacing: usize,
+    pub topology: Topology,
+    pub overlap: Overlap,
+    // Genes are also read from the reverse strand, behind the genes of the
+    // forward strand.
+    pub both_strands: bool,
+}
+
+impl<'b, B: Base + 'b> GeneReading<'b, B> {
+    /// Exact promoter matching on a linear genome with disjoint genes of
+    /// fixed length.
+    pub fn new(promoter: &'b [B], length_of_gene: usize) -> GeneReading<'b, B> {
+        GeneReading {
+            promoter,
+            gene_model: GeneModel::FixedLength(length_of_gene),
+            max_mismatches: 0,
+            max_regulatory_length: None,
+            min_spacing: 0,
+            topology: Topology::Linear,
+            overlap: Overlap::Disjoint,
+            both_strands: false,
+        }
+    }
+
+    // Position and mismatches of the first promoter starting at or behind ```from```.
+    fn find_promoter(&self, s: &[B], from: usize) -> Option<(usize, usize)> {
+        match self.topology {
+            Topology::Linear => {
+                if from > s.len() {
+                    return None;
+                }
+                locate_fuzzy(&s[from..], self.promoter, self.max_mismatches)
+                    .map(|(pos, mismatches)| (pos + from, mismatches))
+            }
+            Topology::Circular => {
+                (from..s.len()).filter_map(|pos| {
+                                   mismatches_circular(s, pos, self.promoter, self.max_mismatches)
+                                       .map(|mismatches| (pos, mismatches))
+                               })
+                               .next()
+            }
+        }
+    }
+
+    // Locates all genes in ```s```, which is the given strand of the genome.
+    fn locate_genes(&self, s: &[B], strand: Strand) -> Vec<GeneSpan> {
+        let n = s.len();
+        let circular = self.topology == Topology::Circular;
+        let mut spans: Vec<GeneSpan> = Vec::new();
+        if circular && self.promoter.len() + self.gene_model.min_extent() > n {
+            return spans;
+        }
+        // regulatory regions do not extend upstream of this position.
+        let mut boundary = 0;
+        let mut from = 0;
+
+        while let Some((pos, mismatches)) = self.find_promoter(s, from) {
+            let gene_start = pos + self.promoter.len();
+            let limit = if circular { pos + n } else { n };
+            let (gene_end, end) = match self.gene_model.locate_end(s, gene_start, limit) {
+                Some(ends) => ends,
+                None => {
+                    if let GeneModel::FixedLength(_) = self.gene_model {
+                        // gene is not complete
+                        break;
+                    }
+                    // no terminator within bounds. try the next promoter.
+                    from = pos + 1;
+                    continue;
+                }
+            };
+
+            let gene_boundary = match self.overlap {
+                Overlap::Disjoint => end,
+                _ => gene_start,
+            };
+
+            if circular {
+                // the gene may not reach into the promoter of the first gene, and
+                // ```min_spacing``` also applies across the end of the sequence.
+                if let Some(first) = spans.first() {
+                    let limit = first.location.promoter.start + n;
+                    if gene_boundary + self.min_spacing > limit {
+                        break;
+                    }
+                }
+            }
+
+            let mut regulatory_length = match self.overlap {
+                Overlap::GenesAndRegulatoryRegions if circular => n - self.promoter.len(),
+                Overlap::GenesAndRegulatoryRegions => pos,
+                _ => pos - boundary,
+            };
+            if let Some(max) = self.max_regulatory_length {
+                regulatory_length = cmp::min(regulatory_length, max);
+            }
+            let regulatory_start = if regulatory_length <= pos {
+                pos - regulatory_length
+            } else {
+                pos + n - regulatory_length
+            };
+
+            spans.push(GeneSpan {
+                location: GeneLocation {
+                    regulatory_region: regulatory_start..regulatory_start + regulatory_length,
+                    promoter: pos..gene_start,
+                    gene: gene_start..gene_end,
+                    terminator: gene_end..end,
+                    strand,
+                },
+                promoter_mismatches: mismatches,
+            });
+            boundary = gene_boundary;
+            from = boundary + self.min_spacing;
+        }
+
+        if circular && !spans.is_empty() && self.overlap != Overlap::GenesAndRegulatoryRegions {
+            // the regulatory region of the first gene starts behind the last gene
+            // (or promoter).
+            let first = &mut spans[0].location;
+            let pos = first.promoter.start;
+            let mut regulatory_length = (pos + n).saturating_sub(boundary);
+            if let Some(max) = self.max_regulatory_length {
+                regulatory_length = cmp::min(regulatory_length, max);
+            }
+            let start = (pos + n - regulatory_length) % n;
+            first.regulatory_region = start..start + regulatory_length;
+        }
+
+        spans
+    }
+}
+
+/// Absolute positions of the parts of a gene in the genome. In a circular
+/// genome, a range may extend beyond the end of the sequence and continues
+/// at its start. Positions of genes on the reverse strand refer to the
+/// reverse complement of the genome (position i of the reverse complement
+/// is position n - 1 - i of the genome).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneLocation {
+    pub regulatory_region: Range<usize>,
+    pub promoter: Range<usize>,
+    pub gene: Range<usize>,
+    // Empty for genes of fixed length.
+    pub terminator: Range<usize>,
+    pub strand: Strand,
+}
+
+#[derive(Debug, Clone)]
+struct GeneSpan {
+    location: GeneLocation,
+    promoter_mismatches: usize,
+}
+
+// The bases of ```s``` in ```range```, wrapping around at the end of ```s```.
+fn circular_slice<'a, B: Base>(s: &'a [B], range: &Range<usize>) -> Cow<'a, [B]> {
+    let len = range.end - range.start;
+    if len == 0 {
+        return Cow::Borrowed(&s[..0]);
+    }
+    let start = range.start % s.len();
+    if start + len <= s.len() {
+        Cow::Borrowed(&s[start..start + len])
+    } else {
+        Cow::Owned(s[start..].iter().chain(s[..start + len - s.len()].iter()).cloned().collect())
+    }
+}
 
+/// A gene together with its regulatory region. Both are borrowed from the
+/// genome, unless they span the end of a circular genome or are read from
+/// the reverse strand.
 #[derive(Debug)]
 pub struct Gene<'a, B: Base + 'a> {
-    pub regulatory_region: &'a [B],
-    pub gene: &'a [B],
+    pub regulatory_region: Cow<'a, [B]>,
+    pub gene: Cow<'a, [B]>,
+    pub location: GeneLocation,
+    // Number of bases in which the promoter of this gene differs from the
+    // promoter sequence.
+    pub promoter_mismatches: usize,
 }
 
 impl<'a, B: Base + 'a> Gene<'a, B> {
     /// The gene product
     pub fn product(&self) -> BaseString<B> {
-        BaseString { v: self.gene.iter().map(|b| b.succ()).collect() }
+        self.translate(&Successor)
+    }
+
+    /// The gene product according to ```translation```.
+    pub fn translate<T: Translation<B>>(&self, translation: &T) -> BaseString<T::Output> {
+        translation.translate(&self.gene)
     }
 
     pub fn find_product_in_regulatory_region(&self, product: &[B]) -> bool {
-        locate_substr(self.regulatory_region, product).is_some()
+        locate_substr(&self.regulatory_region, product).is_some()
     }
     pub fn count_product_in_regulatory_region(&self, product: &[B]) -> usize {
-        count_substr(self.regulatory_region, product)
+        count_substr(&self.regulatory_region, product)
     }
 }
 
-pub struct GeneIterator<'a, 'b, B: Base + 'a + 'b> {
-    // Genes have fixed length
-    length_of_gene: usize,
+pub struct GeneIterator<'a, B: Base + 'a> {
     sequence: &'a [B],
-    promoter: &'b [B],
+    // The reverse complement of ```sequence```, if genes are read from both strands.
+    reverse: Vec<B>,
+    spans: vec::IntoIter<GeneSpan>,
 }
 
-impl<'a, 'b, B: Base + 'a + 'b> Iterator for GeneIterator<'a, 'b, B> {
+impl<'a, B: Base + 'a> Iterator for GeneIterator<'a, B> {
     type Item = Gene<'a, B>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match locate_substr(self.sequence, self.promoter) {
-            Some(pos) => {
-                let gene_start = pos + self.promoter.len();
-                let gene_end = gene_start + self.length_of_gene;
-
-                // gene is not complete
-                if gene_end > self.sequence.len() {
-                    return None;
-                }
-
-                let gene = Gene {
-                    regulatory_region: &self.sequence[..pos],
-                    gene: &self.sequence[gene_start..gene_end],
-                };
-                self.sequence = &self.sequence[gene_end..];
-                return Some(gene);
+        let span = self.spans.next()?;
+        let (regulatory_region, gene) = match span.location.strand {
+            Strand::Forward => {
+                (circular_slice(self.sequence, &span.location.regulatory_region),
+                 circular_slice(self.sequence, &span.location.gene))
             }
-            None => {
-                return None;
+            Strand::Reverse => {
+                let regulatory_region = circular_slice(&self.reverse,
+                                                       &span.location.regulatory_region);
+                let gene = circular_slice(&self.reverse, &span.location.gene);
+                (Cow::Owned(regulatory_region.into_owned()), Cow::Owned(gene.into_owned()))
             }
-        }
+        };
+        Some(Gene {
+            regulatory_region,
+            gene,
+            location: span.location,
+            promoter_mismatches: span.promoter_mismatches,
+        })
     }
 }
 
 // A positive value enhances, a negative inhibits the expression of a gene.
 #[derive(Debug)]
-pub struct ProteinRegulator(i32);
+pub struct ProteinRegulator(f64);
 
 impl ProteinRegulator {
+    pub fn new(weight: f64) -> ProteinRegulator {
+        ProteinRegulator(weight)
+    }
+
     pub fn enhance() -> ProteinRegulator {
-        ProteinRegulator(1)
+        ProteinRegulator(1.0)
     }
 
     pub fn inhibit() -> ProteinRegulator {
-        ProteinRegulator(-1)
+        ProteinRegulator(-1.0)
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.0
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct BaseString<B: Base> {
     v: Vec<B>,
 }
 
+// Parses ```s``` base by base. Characters for which ```skip``` returns true are ignored.
+fn parse_bases<B: Base, F>(s: &str, skip: F) -> Result<Vec<B>, Error>
+    where F: Fn(char) -> bool
+{
+    let mut v = Vec::with_capacity(s.len());
+    for (position, character) in s.chars().enumerate() {
+        if skip(character) {
+            continue;
+        }
+        match B::from_char(character) {
+            Some(base) => v.push(base),
+            None => {
+                return Err(Error::InvalidCharacter {
+                    character,
+                    position,
+                })
+            }
+        }
+    }
+    Ok(v)
+}
+
+/// Same as `BaseString::parse_lenient`.
 impl<B: Base> FromStr for BaseString<B> {
-    type Err = ();
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(BaseString { v: s.chars().filter_map(|c| B::from_char(c)).collect() })
+        BaseString::parse_lenient(s)
     }
 }
 
@@ -153,7 +589,62 @@ impl<B: Base> Debug for BaseString<B> {
     }
 }
 
+impl<B: Base> fmt::Display for BaseString<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for base in &self.v {
+            write!(f, "{}", base.to_char())?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a base string broken into lines of ```width``` bases.
+pub struct Wrapped<'a, B: Base + 'a> {
+    bases: &'a [B],
+    width: usize,
+}
+
+impl<'a, B: Base + 'a> fmt::Display for Wrapped<'a, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, line) in self.bases.chunks(self.width).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for base in line {
+                write!(f, "{}", base.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<B: Base> BaseString<B> {
+    /// Displays the bases with a line break after every ```width``` bases.
+    pub fn wrapped<'a>(&'a self, width: usize) -> Wrapped<'a, B> {
+        assert!(width > 0);
+        Wrapped {
+            bases: &self.v,
+            width,
+        }
+    }
+
+    /// Parses ```s``` and fails at the first character that is not a base,
+    /// including whitespace.
+    pub fn parse_strict(s: &str) -> Result<BaseString<B>, Error> {
+        parse_bases(s, |_| false).map(|v| BaseString { v })
+    }
+
+    /// Parses ```s``` skipping whitespace, but fails at any other character
+    /// that is not a base.
+    pub fn parse_lenient(s: &str) -> Result<BaseString<B>, Error> {
+        parse_bases(s, char::is_whitespace).map(|v| BaseString { v })
+    }
+
+    /// Parses ```s``` silently skipping every character that is not a base.
+    pub fn parse_discarding_invalid(s: &str) -> BaseString<B> {
+        BaseString { v: s.chars().filter_map(B::from_char).collect() }
+    }
+
     pub fn random<R: Rng>(rng: &mut R, n: usize) -> BaseString<B> {
         assert!(n > 0);
         BaseString { v: (0..n).map(|_| rng.gen()).collect() }
@@ -162,7 +653,7 @@ impl<B: Base> BaseString<B> {
 
 
 /// A Genome is a string of Base
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Genome<B: Base> {
     genome: BaseString<B>,
 }
@@ -175,6 +666,37 @@ impl<B: Base> Genome<B> {
     pub fn random<R: Rng>(rng: &mut R, n: usize) -> Genome<B> {
         Genome { genome: BaseString::random(rng, n) }
     }
+
+    /// See `BaseString::wrapped`.
+    pub fn wrapped<'a>(&'a self, width: usize) -> Wrapped<'a, B> {
+        self.genome.wrapped(width)
+    }
+
+    /// The opposite strand, read in its own 5' to 3' direction.
+    pub fn reverse_complement(&self) -> Genome<B> {
+        Genome::from_vec(self.iter().rev().map(|b| b.complement()).collect())
+    }
+
+    /// See `BaseString::parse_strict`.
+    pub fn parse_strict(s: &str) -> Result<Genome<B>, Error> {
+        BaseString::parse_strict(s).map(|bs| Genome { genome: bs })
+    }
+
+    /// See `BaseString::parse_lenient`.
+    pub fn parse_lenient(s: &str) -> Result<Genome<B>, Error> {
+        BaseString::parse_lenient(s).map(|bs| Genome { genome: bs })
+    }
+
+    /// See `BaseString::parse_discarding_invalid`.
+    pub fn parse_discarding_invalid(s: &str) -> Genome<B> {
+        Genome { genome: BaseString::parse_discarding_invalid(s) }
+    }
+}
+
+impl<B: Base> fmt::Display for Genome<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.genome, f)
+    }
 }
 
 impl<B: Base> Deref for Genome<B> {
@@ -201,13 +723,13 @@ impl Node {
         Node { incoming_edges: Vec::new() }
     }
 
-    pub fn sum_edges(&self, network_state: &GeneNetworkState) -> i32 {
-        let mut sum = 0;
+    pub fn sum_edges(&self, network_state: &GeneNetworkState) -> f64 {
+        let mut sum = 0.0;
         for edge in self.incoming_edges.iter() {
             let factor = if network_state.state.contains(edge.src) {
-                1
+                1.0
             } else {
-                0
+                0.0
             };
             sum += factor * edge.weight.0;
         }
@@ -219,6 +741,8 @@ impl Node {
 #[derive(Debug)]
 pub struct GeneNetwork {
     nodes: Vec<Node>,
+    // The gene each node was read from.
+    locations: Vec<GeneLocation>,
 }
 
 #[derive(Debug, Clone)]
@@ -227,15 +751,23 @@ pub struct GeneNetworkState {
 }
 
 impl GeneNetwork {
-    fn new(num_nodes: usize) -> GeneNetwork {
-        assert!(num_nodes > 0);
-        GeneNetwork { nodes: (0..num_nodes).map(|_| Node::new()).collect() }
+    fn new(locations: Vec<GeneLocation>) -> GeneNetwork {
+        assert!(!locations.is_empty());
+        GeneNetwork {
+            nodes: locations.iter().map(|_| Node::new()).collect(),
+            locations,
+        }
     }
 
     pub fn nodes(&self) -> &[Node] {
         &self.nodes
     }
 
+    /// The location in the genome of the gene of each node.
+    pub fn locations(&self) -> &[GeneLocation] {
+        &self.locations
+    }
+
     fn add_edge(&mut self, src: usize, dst: usize, weight: ProteinRegulator) {
         assert!(src < self.nodes.len());
         assert!(dst < self.nodes.len());
@@ -252,14 +784,21 @@ impl GeneNetwork {
 
 // Convert genome into sections, i.e. Split at the promoter.
 impl<B: Base> Genome<B> {
-    pub fn iter_genes<'a, 'b>(&'a self,
-                              promoter: &'b [B],
-                              length_of_gene: usize)
-                              -> GeneIterator<'a, 'b, B> {
+    pub fn iter_genes<'a>(&'a self, promoter: &[B], length_of_gene: usize) -> GeneIterator<'a, B> {
+        self.read_genes(&GeneReading::new(promoter, length_of_gene))
+    }
+
+    pub fn read_genes<'a>(&'a self, reading: &GeneReading<B>) -> GeneIterator<'a, B> {
+        let mut spans = reading.locate_genes(&self.genome, Strand::Forward);
+        let mut reverse = Vec::new();
+        if reading.both_strands {
+            reverse = self.reverse_complement().genome.v;
+            spans.extend(reading.locate_genes(&reverse, Strand::Reverse));
+        }
         GeneIterator {
-            length_of_gene: length_of_gene,
             sequence: &self.genome,
-            promoter: promoter,
+            reverse,
+            spans: spans.into_iter(),
         }
     }
 
@@ -268,44 +807,662 @@ impl<B: Base> Genome<B> {
                                 promoter: &[B],
                                 length_of_gene: usize,
                                 protein_regulation: &F)
-                                -> Option<GeneNetwork>
+                                -> Result<GeneNetwork, Error>
+        where F: Fn(&[B]) -> ProteinRegulator
+    {
+        self.construct_network_with(&GeneReading::new(promoter, length_of_gene),
+                                    protein_regulation)
+    }
+
+    // Construct a dependency network between the genes read according to ```reading```.
+    pub fn construct_network_with<F>(&self,
+                                     reading: &GeneReading<B>,
+                                     protein_regulation: &F)
+                                     -> Result<GeneNetwork, Error>
         where F: Fn(&[B]) -> ProteinRegulator
     {
-        let genes: Vec<_> = self.iter_genes(promoter, length_of_gene).collect();
-        let num_genes = genes.len();
+        self.construct_network_translated(reading, &Successor, protein_regulation)
+    }
 
-        if num_genes == 0 {
-            return None;
+    // Like ```construct_network_with```, but the gene products are produced by
+    // ```translation```. Empty products do not regulate any gene. Products over
+    // another alphabet require ```construct_network_translated_with_binding```.
+    pub fn construct_network_translated<F, T>(&self,
+                                              reading: &GeneReading<B>,
+                                              translation: &T,
+                                              protein_regulation: &F)
+                                              -> Result<GeneNetwork, Error>
+        where F: Fn(&[B]) -> ProteinRegulator,
+              T: Translation<B, Output = B>
+    {
+        let genes: Vec<_> = self.read_genes(reading).collect();
+        if genes.is_empty() {
+            return Err(self.no_genes_error(reading));
         }
 
         // each gene is a node in the boolean network
-        let mut network = GeneNetwork::new(num_genes);
+        let mut network = GeneNetwork::new(genes.iter().map(|g| g.location.clone()).collect());
+
+        let products: Vec<BaseString<B>> = genes.iter()
+                                                .map(|gene| gene.translate(translation))
+                                                .collect();
+
+        // empty products do not regulate any gene.
+        let sources: Vec<usize> = (0..products.len())
+                                      .filter(|&i| !products[i].is_empty())
+                                      .collect();
+
+        // A gene product either enhances (> 0) or inyhibits (< 0) the expression of
+        // another gene.
+        let regulators: Vec<ProteinRegulator> = sources.iter()
+                                                       .map(|&i| protein_regulation(&products[i]))
+                                                       .collect();
+
+        // search all products at once in the regulatory region of each gene.
+        let patterns: Vec<&[B]> = sources.iter().map(|&i| &products[i][..]).collect();
+        let index = PatternIndex::new(&patterns);
+
+        for (dst, gene) in genes.iter().enumerate() {
+            // XXX: Can a gene regulate itself?
+            let counts = index.count_all(&gene.regulatory_region);
+            for ((&src, regulator), &factor) in sources.iter()
+                                                       .zip(regulators.iter())
+                                                       .zip(counts.iter()) {
+                if factor > 0 {
+                    network.add_edge(src, dst, ProteinRegulator(regulator.0 * factor as f64));
+                }
+            }
+        }
+
+        Ok(network)
+    }
+
+    // Like ```construct_network_with```, but the regulatory strength of a product
+    // is determined by ```binding```, resulting in real-valued edge weights.
+    // Empty products do not regulate any gene.
+    pub fn construct_network_with_binding<F, Bi>(&self,
+                                                 reading: &GeneReading<B>,
+                                                 binding: &Bi,
+                                                 protein_regulation: &F)
+                                                 -> Result<GeneNetwork, Error>
+        where F: Fn(&[B]) -> ProteinRegulator,
+              Bi: Binding<B>
+    {
+        self.construct_network_translated_with_binding(reading,
+                                                       &Successor,
+                                                       binding,
+                                                       protein_regulation)
+    }
+
+    // Like ```construct_network_with_binding```, but the gene products are
+    // produced by ```translation```. The products may use a different alphabet
+    // than the genome, e.g. proteins translated by a `GeneticCode`, as long as
+    // ```binding``` relates them to the regulatory regions.
+    pub fn construct_network_translated_with_binding<F, T, Bi>(&self,
+                                                               reading: &GeneReading<B>,
+                                                               translation: &T,
+                                                               binding: &Bi,
+                                                               protein_regulation: &F)
+                                                               -> Result<GeneNetwork, Error>
+        where T: Translation<B>,
+              F: Fn(&[T::Output]) -> ProteinRegulator,
+              Bi: Binding<T::Output, B>
+    {
+        let genes: Vec<_> = self.read_genes(reading).collect();
+        if genes.is_empty() {
+            return Err(self.no_genes_error(reading));
+        }
+
+        let mut network = GeneNetwork::new(genes.iter().map(|g| g.location.clone()).collect());
 
         for (src, gene) in genes.iter().enumerate() {
-            let product = gene.product();
-            // A gene product either enhances (> 0) or inyhibits (< 0) the expression of
-            // another gene.
+            let product = gene.translate(translation);
+            if product.is_empty() {
+                continue;
+            }
             let regulator = protein_regulation(&product);
 
-            // determine which other genes ```gene``` regulates
             for (dst, gene2) in genes.iter().enumerate() {
-                // XXX: Can a gene regulate itself?
-                // if src != dst {
-                let factor = gene2.count_product_in_regulatory_region(&product);
-                if factor > 0 {
-                    network.add_edge(src, dst, ProteinRegulator(regulator.0 * factor as i32));
+                let strength = binding.strength(&product, &gene2.regulatory_region);
+                if strength != 0.0 {
+                    network.add_edge(src, dst, ProteinRegulator(regulator.0 * strength));
                 }
-                // }
             }
         }
 
-        return Some(network);
+        Ok(network)
+    }
+
+    // Without any complete gene, the first promoter (if any) is truncated.
+    fn no_genes_error(&self, reading: &GeneReading<B>) -> Error {
+        let mut found = reading.find_promoter(&self.genome, 0)
+                               .map(|(position, _)| (position, Strand::Forward));
+        if found.is_none() && reading.both_strands {
+            found = reading.find_promoter(&self.reverse_complement(), 0)
+                           .map(|(position, _)| (position, Strand::Reverse));
+        }
+        match (found, &reading.gene_model) {
+            (Some((position, strand)), &GeneModel::FixedLength(_)) => {
+                Error::TruncatedGene { position, strand }
+            }
+            (Some((position, strand)), &GeneModel::Terminated { .. }) => {
+                Error::NoTerminator { position, strand }
+            }
+            (None, _) => Error::NoPromoterFound,
+        }
     }
 }
 
+/// Same as `Genome::parse_lenient`.
 impl<B: Base> FromStr for Genome<B> {
-    type Err = ();
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         FromStr::from_str(s).map(|bs| Genome { genome: bs })
     }
 }
+
+#[test]
+fn test_construct_network_errors() {
+    use base4::{Base4, B0, B1};
+    let promoter = [B0, B1, B0, B1];
+    let regulation = |_: &[Base4]| ProteinRegulator::enhance();
+
+    let genome: Genome<Base4> = "2222 3333".parse().unwrap();
+    match genome.construct_network(&promoter, 4, &regulation) {
+        Err(Error::NoPromoterFound) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let genome: Genome<Base4> = "22 0101 333".parse().unwrap();
+    match genome.construct_network(&promoter, 4, &regulation) {
+        Err(Error::TruncatedGene { position: 2, strand: Strand::Forward }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let genome: Genome<Base4> = "22 0101 3333".parse().unwrap();
+    assert_eq!(1, genome.construct_network(&promoter, 4, &regulation).unwrap().nodes().len());
+}
+
+#[test]
+fn test_development_limit_exceeded() {
+    use base4::Base4;
+    use graph::graph_from_base4_genome_with_limit;
+
+    // the product 3333 of the first gene enables the second gene (EDGE_SPLIT).
+    let genome: Genome<Base4> = "0101 2222 3333 0101 2222".parse().unwrap();
+    match graph_from_base4_genome_with_limit(&genome, 1, 1) {
+        Err(Error::DevelopmentLimitExceeded { limit: 1 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    let result = graph_from_base4_genome_with_limit(&genome, 1, 2);
+    assert!(!matches!(result, Err(Error::DevelopmentLimitExceeded { .. })));
+}
+
+#[test]
+fn test_no_processing_nodes() {
+    use base4::Base4;
+    use graph::graph_from_base4_genome;
+
+    // a single gene never activates EDGE_TYPE.
+    let genome: Genome<Base4> = "0101 2222".parse().unwrap();
+    match graph_from_base4_genome(&genome, 3) {
+        Err(Error::NoProcessingNodes) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_modes() {
+    use dna_base::DNABase::{self, A, T, G, C};
+
+    match Genome::<DNABase>::parse_strict("AT GC") {
+        Err(Error::InvalidCharacter { character: ' ', position: 2 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(&[A, T, G, C], &Genome::<DNABase>::parse_strict("ATGC").unwrap()[..]);
+
+    match "A T G X C".parse::<Genome<DNABase>>() {
+        Err(Error::InvalidCharacter { character: 'X', position: 6 }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(&[A, T, G, C], &Genome::<DNABase>::parse_lenient("A T\nG C").unwrap()[..]);
+
+    assert_eq!(&[A, T, G, C], &Genome::<DNABase>::parse_discarding_invalid("A T G X C")[..]);
+}
+
+#[test]
+fn test_display_round_trip() {
+    use dna_base::DNABase;
+    use base4::Base4;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+    let genome = Genome::<DNABase>::random(&mut rng, 100);
+    assert_eq!(genome, genome.to_string().parse().unwrap());
+    assert_eq!(genome, Genome::parse_strict(&genome.to_string()).unwrap());
+    assert_eq!(genome, genome.wrapped(7).to_string().parse().unwrap());
+
+    let genome = Genome::<Base4>::random(&mut rng, 100);
+    assert_eq!(genome, genome.to_string().parse().unwrap());
+    assert_eq!(genome, genome.wrapped(60).to_string().parse().unwrap());
+
+    let genome: Genome<Base4> = "0123".parse().unwrap();
+    assert_eq!("0123", genome.to_string());
+    assert_eq!("01\n23", genome.wrapped(2).to_string());
+}
+
+#[test]
+fn test_construct_network_matches_pairwise_count() {
+    use base4::{Base4, B0, B1};
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<Base4>::random(&mut rng, 20000);
+    let promoter = [B0, B1, B0, B1];
+    let regulation = |product: &[Base4]| {
+        if product[0] == B0 {
+            ProteinRegulator::inhibit()
+        } else {
+            ProteinRegulator::enhance()
+        }
+    };
+
+    let network = genome.construct_network(&promoter, 4, &regulation).unwrap();
+    let genes: Vec<_> = genome.iter_genes(&promoter, 4).collect();
+    assert!(genes.len() > 10);
+
+    let mut num_edges = 0;
+    for (src, gene) in genes.iter().enumerate() {
+        let product = gene.product();
+        for (dst, gene2) in genes.iter().enumerate() {
+            let factor = gene2.count_product_in_regulatory_region(&product) as f64;
+            let weight: f64 = network.nodes()[dst]
+                                  .incoming_edges
+                                  .iter()
+                                  .filter(|edge| edge.src == src)
+                                  .map(|edge| edge.weight.0)
+                                  .sum();
+            assert_eq!(regulation(&product).0 * factor, weight);
+            if factor > 0.0 {
+                num_edges += 1;
+            }
+        }
+    }
+    assert_eq!(num_edges,
+               network.nodes().iter().map(|node| node.incoming_edges.len()).sum::<usize>());
+}
+
+#[test]
+fn test_fuzzy_promoter() {
+    use base4::{Base4, B0, B1};
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "22 0111 3333 2 0101 3232".parse().unwrap();
+
+    let genes: Vec<_> = genome.iter_genes(&promoter, 4).collect();
+    assert_eq!(1, genes.len());
+    assert_eq!(0, genes[0].promoter_mismatches);
+
+    let reading = GeneReading { max_mismatches: 1, ..GeneReading::new(&promoter, 4) };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(2, genes.len());
+    assert_eq!((1, 2), (genes[0].promoter_mismatches, genes[0].regulatory_region.len()));
+    assert_eq!((0, 1), (genes[1].promoter_mismatches, genes[1].regulatory_region.len()));
+
+    let network = genome.construct_network_with(&reading, &|_| ProteinRegulator::enhance());
+    assert_eq!(2, network.unwrap().nodes().len());
+}
+
+#[test]
+fn test_construct_network_with_binding() {
+    use base4::{Base4, B0, B1};
+    use binding::{AffinityBinding, Aggregate, ExactBinding, hamming_affinity};
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<Base4>::random(&mut rng, 5000);
+    let promoter = [B0, B1, B0, B1];
+    let reading = GeneReading::new(&promoter, 4);
+    let regulation = |_: &[Base4]| ProteinRegulator::inhibit();
+
+    let weights = |network: &GeneNetwork| -> Vec<Vec<(usize, f64)>> {
+        network.nodes()
+               .iter()
+               .map(|node| node.incoming_edges.iter().map(|e| (e.src, e.weight.0)).collect())
+               .collect()
+    };
+
+    let exact = genome.construct_network_with(&reading, &regulation).unwrap();
+    let bound = genome.construct_network_with_binding(&reading, &ExactBinding, &regulation)
+                      .unwrap();
+    assert_eq!(weights(&exact), weights(&bound));
+
+    let affinity = AffinityBinding {
+        score: hamming_affinity::<Base4>,
+        aggregate: Aggregate::Best,
+        threshold: 0.75,
+    };
+    let network = genome.construct_network_with_binding(&reading, &affinity, &regulation)
+                        .unwrap();
+    let affinity_weights = weights(&network);
+    assert!(affinity_weights.iter().flat_map(|w| w.iter()).any(|&(_, w)| w == -0.75));
+    assert!(affinity_weights.iter().flat_map(|w| w.iter()).all(|&(_, w)| w == -0.75 || w == -1.0));
+}
+
+#[test]
+fn test_bounded_regulatory_region() {
+    use base4::{Base4, B0, B1, B2, B3};
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "2323 0101 3333 22 0101 3232 0101 2222".parse().unwrap();
+
+    let reading = GeneReading { max_regulatory_length: Some(3), ..GeneReading::new(&promoter, 4) };
+    let lengths: Vec<_> = genome.read_genes(&reading).map(|g| g.regulatory_region.len()).collect();
+    assert_eq!(vec![3, 2, 0], lengths);
+
+    let reading = GeneReading { min_spacing: 1, ..GeneReading::new(&promoter, 4) };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    // the promoter directly behind the second gene is ignored.
+    assert_eq!(2, genes.len());
+    assert_eq!(&[B3, B2, B3, B2], &genes[1].gene[..]);
+    assert_eq!(2, genes[1].regulatory_region.len());
+}
+
+#[test]
+fn test_circular_genome() {
+    use base4::{Base4, B0, B1, B2, B3};
+    let promoter = [B0, B1, B0, B1];
+
+    // the second gene wraps around the end.
+    let genome: Genome<Base4> = "33 22 0101 3333 2 0101 22".parse().unwrap();
+    assert_eq!(1, genome.iter_genes(&promoter, 4).count());
+
+    let reading = GeneReading { topology: Topology::Circular, ..GeneReading::new(&promoter, 4) };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(2, genes.len());
+    assert_eq!(&[B3, B3, B3, B3], &genes[0].gene[..]);
+    assert_eq!(&[B2, B2, B3, B3], &genes[1].gene[..]);
+    assert_eq!(&[B2], &genes[1].regulatory_region[..]);
+    // the regulatory region of the first gene starts behind the wrapped second gene.
+    assert_eq!(&[B2, B2], &genes[0].regulatory_region[..]);
+
+    // a promoter spanning the end.
+    let genome: Genome<Base4> = "01 2222 3 01".parse().unwrap();
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(1, genes.len());
+    assert_eq!(&[B2, B2, B2, B2], &genes[0].gene[..]);
+    assert_eq!(&[B3], &genes[0].regulatory_region[..]);
+
+    // the second gene ends right at the first promoter.
+    let genome: Genome<Base4> = "0101 2222 3333 0101 2222".parse().unwrap();
+    assert_eq!(2, genome.read_genes(&reading).count());
+    let spaced = GeneReading { min_spacing: 3, ..reading };
+    assert_eq!(1, genome.read_genes(&spaced).count());
+}
+
+#[test]
+fn test_overlapping_genes() {
+    use base4::{Base4, B0, B1, B2, B3};
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "2 0101 0101 3333 0101 22".parse().unwrap();
+
+    let offsets: Vec<_> =
+        genome.iter_genes(&promoter, 4).map(|g| g.location.promoter.start).collect();
+    assert_eq!(vec![1], offsets);
+
+    // the promoter inside the first gene starts a second gene.
+    let reading = GeneReading { overlap: Overlap::Genes, ..GeneReading::new(&promoter, 4) };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(vec![1, 5], genes.iter().map(|g| g.location.promoter.start).collect::<Vec<_>>());
+    assert_eq!(&[B0, B1, B0, B1], &genes[0].gene[..]);
+    assert_eq!(&[B3, B3, B3, B3], &genes[1].gene[..]);
+    assert_eq!(&[B2], &genes[0].regulatory_region[..]);
+    assert!(genes[1].regulatory_region.is_empty());
+
+    let reading = GeneReading { overlap: Overlap::GenesAndRegulatoryRegions, ..reading };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(2, genes.len());
+    assert_eq!(&[B2, B0, B1, B0, B1], &genes[1].regulatory_region[..]);
+
+    let reading = GeneReading { topology: Topology::Circular, ..reading };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(vec![1, 5, 13], genes.iter().map(|g| g.location.promoter.start).collect::<Vec<_>>());
+    assert_eq!(15, genes[0].regulatory_region.len());
+}
+
+#[test]
+fn test_gene_locations() {
+    use base4::{Base4, B0, B1};
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "22 0101 3333 2 0101 3232 2".parse().unwrap();
+
+    let genes: Vec<_> = genome.iter_genes(&promoter, 4).collect();
+    assert_eq!(GeneLocation {
+                   regulatory_region: 10..11,
+                   promoter: 11..15,
+                   gene: 15..19,
+                   terminator: 19..19,
+                   strand: Strand::Forward,
+               },
+               genes[1].location);
+    for gene in genes.iter() {
+        assert_eq!(&genome[gene.location.gene.clone()], &gene.gene[..]);
+        assert_eq!(&genome[gene.location.regulatory_region.clone()],
+                   &gene.regulatory_region[..]);
+    }
+
+    let network = genome.construct_network(&promoter, 4, &|_| ProteinRegulator::enhance())
+                        .unwrap();
+    assert_eq!(genes.iter().map(|g| g.location.clone()).collect::<Vec<_>>(),
+               network.locations());
+}
+
+#[test]
+fn test_terminated_genes() {
+    use base4::{Base4, B0, B1, B2, B3};
+    let promoter = [B0, B1, B0, B1];
+    let terminator = [B3, B3];
+    let genome: Genome<Base4> = "2 0101 122 33 0101 1 33 0101 2121212 33 0101 2222 33"
+                                    .parse()
+                                    .unwrap();
+
+    let reading = GeneReading {
+        gene_model: GeneModel::Terminated {
+            terminator: &terminator,
+            min_length: 2,
+            max_length: 6,
+        },
+        ..GeneReading::new(&promoter, 0)
+    };
+    // the genes of the second and third promoter are too short or too long.
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(2, genes.len());
+    assert_eq!(&[B1, B2, B2], &genes[0].gene[..]);
+    assert_eq!(8..10, genes[0].location.terminator);
+    assert_eq!(&[B2, B2, B2, B2], &genes[1].gene[..]);
+    assert_eq!(10..30, genes[1].location.regulatory_region);
+
+    let network = genome.construct_network_with(&reading, &|_| ProteinRegulator::enhance());
+    assert_eq!(2, network.unwrap().nodes().len());
+
+    let genome: Genome<Base4> = "0101 2222".parse().unwrap();
+    match genome.construct_network_with(&reading, &|_| ProteinRegulator::enhance()) {
+        Err(Error::NoTerminator { position: 0, strand: Strand::Forward }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // only the reverse strand "1111 0101" contains a promoter.
+    let genome: Genome<Base4> = "2323 2222".parse().unwrap();
+    let reading = GeneReading { both_strands: true, ..reading };
+    match genome.construct_network_with(&reading, &|_| ProteinRegulator::enhance()) {
+        Err(Error::NoTerminator { position: 4, strand: Strand::Reverse }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_both_strands() {
+    use base4::{Base4, B0, B1, B2, B3};
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "22 0000 2323 0101 1010 22".parse().unwrap();
+    assert_eq!("11 3232 2323 0101 3333 11".parse::<Genome<Base4>>().unwrap(),
+               genome.reverse_complement());
+    assert_eq!(genome, genome.reverse_complement().reverse_complement());
+
+    let reading = GeneReading { both_strands: true, ..GeneReading::new(&promoter, 4) };
+    let genes: Vec<_> = genome.read_genes(&reading).collect();
+    assert_eq!(vec![Strand::Forward, Strand::Reverse],
+               genes.iter().map(|g| g.location.strand).collect::<Vec<_>>());
+    assert_eq!(&[B1, B0, B1, B0], &genes[0].gene[..]);
+    assert_eq!(&[B3, B3, B3, B3], &genes[1].gene[..]);
+    assert_eq!(&[B1, B1, B3, B2, B3, B2, B2, B3, B2, B3], &genes[1].regulatory_region[..]);
+    assert_eq!(14..18, genes[1].location.gene);
+
+    let network = genome.construct_network_with(&reading, &|_| ProteinRegulator::enhance())
+                        .unwrap();
+    assert_eq!(2, network.nodes().len());
+    // both genes have the same ranges, but on different strands.
+    let locations = network.locations();
+    assert_eq!(locations[0].gene, locations[1].gene);
+    assert_eq!((Strand::Forward, Strand::Reverse),
+               (locations[0].strand, locations[1].strand));
+}
+
+#[test]
+fn test_construct_network_translated() {
+    use base4::{Base4, B0, B1, B2, B3};
+    use translation::{CodonTable, PerBase};
+    let promoter = [B0, B1, B0, B1];
+    let regulation = |_: &[Base4]| ProteinRegulator::enhance();
+    let sources = |network: &GeneNetwork| -> Vec<Vec<usize>> {
+        network.nodes()
+               .iter()
+               .map(|node| node.incoming_edges.iter().map(|e| e.src).collect())
+               .collect()
+    };
+    let none: Vec<usize> = Vec::new();
+
+    // the product of the first gene is 3333 with ```Successor```, and 1111 with
+    // the complement.
+    let genome: Genome<Base4> = "2332 0101 2222 1111 0101 1212".parse().unwrap();
+    let reading = GeneReading::new(&promoter, 4);
+
+    let network = genome.construct_network_with(&reading, &regulation).unwrap();
+    assert_eq!(vec![none.clone(), none.clone()], sources(&network));
+
+    let complement = PerBase(|b: Base4| b.complement());
+    let network = genome.construct_network_translated(&reading, &complement, &regulation)
+                        .unwrap();
+    assert_eq!(vec![none.clone(), vec![0]], sources(&network));
+
+    // 22 translates to 33, all other codons are skipped.
+    let mut table = CodonTable::new(2);
+    table.insert(&[B2, B2], B3);
+    let network = genome.construct_network_translated(&reading, &table, &regulation).unwrap();
+    assert_eq!(vec![vec![0], none], sources(&network));
+
+    // the regulation is not applied to the empty product of the second gene.
+    let by_first_base = |product: &[Base4]| {
+        if product[0] == B0 {
+            ProteinRegulator::inhibit()
+        } else {
+            ProteinRegulator::enhance()
+        }
+    };
+    let network = genome.construct_network_translated(&reading, &table, &by_first_base).unwrap();
+    assert_eq!(1.0, network.nodes()[0].incoming_edges[0].weight.0);
+}
+
+#[test]
+fn test_empty_terminated_genes() {
+    use base4::{Base4, B0, B1, B3};
+    use binding::{AffinityBinding, Aggregate, ExactBinding, hamming_affinity};
+    let promoter = [B0, B1, B0, B1];
+    let terminator = [B3, B3];
+    let genome: Genome<Base4> = "2 0101 33 2222 0101 1 33 22".parse().unwrap();
+
+    let reading = GeneReading {
+        gene_model: GeneModel::Terminated {
+            terminator: &terminator,
+            min_length: 0,
+            max_length: usize::MAX,
+        },
+        ..GeneReading::new(&promoter, 0)
+    };
+    let lengths: Vec<_> = genome.read_genes(&reading).map(|g| g.gene.len()).collect();
+    assert_eq!(vec![0, 1], lengths);
+
+    let regulation = |_: &[Base4]| ProteinRegulator::enhance();
+    let network = genome.construct_network_with_binding(&reading, &ExactBinding, &regulation);
+    assert_eq!(2, network.unwrap().nodes().len());
+    let affinity = AffinityBinding {
+        score: hamming_affinity::<Base4>,
+        aggregate: Aggregate::Sum,
+        threshold: 0.0,
+    };
+    let network = genome.construct_network_with_binding(&reading, &affinity, &regulation);
+    assert_eq!(2, network.unwrap().nodes().len());
+    let network = genome.construct_network_with(&reading, &regulation);
+    assert_eq!(2, network.unwrap().nodes().len());
+}
+
+#[test]
+fn test_construct_network_with_proteins() {
+    use amino_acid::{AminoAcid, GeneticCode};
+    use binding::{AffinityBinding, Aggregate};
+    use dna_base::DNABase::{self, A, T};
+    let promoter = [T, A, T, A];
+
+    // an amino acid binds to the DNA base with its index modulo 4.
+    let score = |protein: &[AminoAcid], dna: &[DNABase]| -> f64 {
+        let matches = protein.iter().zip(dna.iter()).filter(|&(p, d)| p.index() % 4 == d.index());
+        matches.count() as f64 / protein.len() as f64
+    };
+    let binding = AffinityBinding {
+        score,
+        aggregate: Aggregate::Best,
+        threshold: 1.0,
+    };
+
+    // ATG GCG translates to Met Ala, which binds to AA. The second gene has no
+    // start codon.
+    let genome: Genome<DNABase> = "C TATA ATGGCG CCAACC TATA CCCCCC".parse().unwrap();
+    let reading = GeneReading::new(&promoter, 6);
+    let code = GeneticCode::standard();
+    let regulation = |_: &[AminoAcid]| ProteinRegulator::enhance();
+    let network = genome.construct_network_translated_with_binding(&reading,
+                                                                   &code,
+                                                                   &binding,
+                                                                   &regulation)
+                        .unwrap();
+    let sources: Vec<Vec<usize>> = network.nodes()
+                                          .iter()
+                                          .map(|n| n.incoming_edges.iter().map(|e| e.src).collect())
+                                          .collect();
+    assert_eq!(vec![vec![], vec![0]], sources);
+}
+
+#[test]
+fn test_alphabet_introspection() {
+    use amino_acid::AminoAcid;
+    use base4::Base4;
+    use base_n::BaseN;
+    use dna_base::DNABase;
+    use rna_base::RNABase;
+
+    fn check<B: Base>() {
+        assert_eq!(B::cardinality(), B::symbols().count());
+        for (i, b) in B::symbols().enumerate() {
+            assert_eq!(i, b.index());
+            assert_eq!(b, B::from_index(b.index()));
+            assert_eq!(Some(b), B::from_char(b.to_char()));
+        }
+    }
+
+    check::<DNABase>();
+    check::<RNABase>();
+    check::<Base4>();
+    check::<AminoAcid>();
+    check::<BaseN<2>>();
+    check::<BaseN<36>>();
+}
+
@@ -0,0 +1,284 @@
+use super::{Base, Genome};
+use rand::Rng;
+use std::cmp;
+
+/// Rates of the mutation operators applied by `Genome::mutate`.
+///
+/// ```point``` is a per-base probability. All other rates are the
+/// probability that the respective structural operator is applied
+/// (once) to the genome.
+#[derive(Debug, Clone)]
+pub struct MutationRates {
+    pub point: f64,
+    pub insertion: f64,
+    pub deletion: f64,
+    pub duplication: f64,
+    pub inversion: f64,
+    pub transposition: f64,
+
+    // Upper bound of the length of a segment affected by a structural operator.
+    pub max_segment_length: usize,
+}
+
+impl Default for MutationRates {
+    fn default() -> MutationRates {
+        MutationRates {
+            point: 0.001,
+            insertion: 0.05,
+            deletion: 0.05,
+            duplication: 0.05,
+            inversion: 0.05,
+            transposition: 0.05,
+            max_segment_length: 16,
+        }
+    }
+}
+
+/// A mutation that was applied to a genome. Positions refer to the genome
+/// as it was right before the mutation was applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mutation<B: Base> {
+    Point {
+        position: usize,
+        from: B,
+        to: B,
+    },
+    Insertion {
+        position: usize,
+        bases: Vec<B>,
+    },
+    Deletion {
+        position: usize,
+        length: usize,
+    },
+    Duplication {
+        source: usize,
+        length: usize,
+        destination: usize,
+    },
+    Inversion {
+        position: usize,
+        length: usize,
+    },
+    // ```destination``` is relative to the genome with the segment already cut out.
+    Transposition {
+        source: usize,
+        length: usize,
+        destination: usize,
+    },
+}
+
+/// The mutations applied by `Genome::mutate`, in order of application.
+#[derive(Debug, Clone)]
+pub struct MutationReport<B: Base> {
+    pub mutations: Vec<Mutation<B>>,
+}
+
+impl<B: Base> MutationReport<B> {
+    pub fn is_empty(&self) -> bool {
+        self.mutations.is_empty()
+    }
+}
+
+// Draw a random base that differs from ```base```.
+fn different_base<B: Base, R: Rng>(rng: &mut R, base: B) -> B {
+    let n = B::cardinality();
+    assert!(n > 1);
+    B::from_index((base.index() + rng.gen_range(1, n)) % n)
+}
+
+// Draw the length of a segment within a sequence of length ```len```.
+fn segment_length<R: Rng>(rng: &mut R, len: usize, max_segment_length: usize) -> usize {
+    rng.gen_range(1, cmp::min(len, max_segment_length) + 1)
+}
+
+impl<B: Base> Genome<B> {
+    /// Replaces the base at ```position```.
+    pub fn substitute(&mut self, position: usize, base: B) {
+        self.genome.v[position] = base;
+    }
+
+    /// Inserts ```bases``` in front of ```position```.
+    pub fn insert_segment(&mut self, position: usize, bases: &[B]) {
+        assert!(position <= self.genome.v.len());
+        let tail = self.genome.v.split_off(position);
+        self.genome.v.extend_from_slice(bases);
+        self.genome.v.extend(tail);
+    }
+
+    /// Removes ```length``` bases starting at ```position```.
+    pub fn delete_segment(&mut self, position: usize, length: usize) {
+        self.genome.v.drain(position..position + length);
+    }
+
+    /// Inserts a copy of the segment ```[source, source + length)``` in front
+    /// of ```destination```.
+    pub fn duplicate_segment(&mut self, source: usize, length: usize, destination: usize) {
+        let segment = self.genome.v[source..source + length].to_vec();
+        self.insert_segment(destination, &segment);
+    }
+
+    /// Reverses the order of the bases in ```[position, position + length)```.
+    pub fn invert_segment(&mut self, position: usize, length: usize) {
+        self.genome.v[position..position + length].reverse();
+    }
+
+    /// Cuts out the segment ```[source, source + length)``` and reinserts it
+    /// in front of ```destination``` of the remaining genome.
+    pub fn transpose_segment(&mut self, source: usize, length: usize, destination: usize) {
+        let segment: Vec<B> = self.genome.v.drain(source..source + length).collect();
+        self.insert_segment(destination, &segment);
+    }
+
+    /// Randomly mutates the genome according to ```rates```.
+    ///
+    /// The structural operators are tried first, in the order insertion,
+    /// deletion, duplication, inversion and transposition, followed by the
+    /// point substitutions. A deletion never removes the last base.
+    pub fn mutate<R: Rng>(&mut self, rng: &mut R, rates: &MutationRates) -> MutationReport<B> {
+        assert!(rates.max_segment_length > 0);
+        let mut mutations = Vec::new();
+
+        if rng.gen::<f64>() < rates.insertion {
+            let length = rng.gen_range(1, rates.max_segment_length + 1);
+            let position = rng.gen_range(0, self.genome.v.len() + 1);
+            let bases: Vec<B> = (0..length).map(|_| rng.gen()).collect();
+            self.insert_segment(position, &bases);
+            mutations.push(Mutation::Insertion { position, bases });
+        }
+
+        if self.genome.v.len() > 1 && rng.gen::<f64>() < rates.deletion {
+            let len = self.genome.v.len();
+            let length = segment_length(rng, len - 1, rates.max_segment_length);
+            let position = rng.gen_range(0, len - length + 1);
+            self.delete_segment(position, length);
+            mutations.push(Mutation::Deletion { position, length });
+        }
+
+        if !self.genome.v.is_empty() && rng.gen::<f64>() < rates.duplication {
+            let len = self.genome.v.len();
+            let length = segment_length(rng, len, rates.max_segment_length);
+            let source = rng.gen_range(0, len - length + 1);
+            let destination = rng.gen_range(0, len + 1);
+            self.duplicate_segment(source, length, destination);
+            mutations.push(Mutation::Duplication {
+                source,
+                length,
+                destination,
+            });
+        }
+
+        if !self.genome.v.is_empty() && rng.gen::<f64>() < rates.inversion {
+            let len = self.genome.v.len();
+            let length = segment_length(rng, len, rates.max_segment_length);
+            let position = rng.gen_range(0, len - length + 1);
+            self.invert_segment(position, length);
+            mutations.push(Mutation::Inversion { position, length });
+        }
+
+        if !self.genome.v.is_empty() && rng.gen::<f64>() < rates.transposition {
+            let len = self.genome.v.len();
+            let length = segment_length(rng, len, rates.max_segment_length);
+            let source = rng.gen_range(0, len - length + 1);
+            let destination = rng.gen_range(0, len - length + 1);
+            self.transpose_segment(source, length, destination);
+            mutations.push(Mutation::Transposition {
+                source,
+                length,
+                destination,
+            });
+        }
+
+        if rates.point > 0.0 {
+            for position in 0..self.genome.v.len() {
+                if rng.gen::<f64>() < rates.point {
+                    let from = self.genome.v[position];
+                    let to = different_base(rng, from);
+                    self.substitute(position, to);
+                    mutations.push(Mutation::Point { position, from, to });
+                }
+            }
+        }
+
+        MutationReport { mutations }
+    }
+}
+
+#[test]
+fn test_segment_operators() {
+    use super::dna_base::DNABase::*;
+    let mut genome = Genome::from_vec(vec![A, T, G, C]);
+
+    genome.insert_segment(2, &[A, A]);
+    assert_eq!(&[A, T, A, A, G, C], &genome[..]);
+
+    genome.delete_segment(1, 3);
+    assert_eq!(&[A, G, C], &genome[..]);
+
+    genome.duplicate_segment(1, 2, 0);
+    assert_eq!(&[G, C, A, G, C], &genome[..]);
+
+    genome.invert_segment(0, 3);
+    assert_eq!(&[A, C, G, G, C], &genome[..]);
+
+    genome.transpose_segment(0, 2, 3);
+    assert_eq!(&[G, G, C, A, C], &genome[..]);
+}
+
+#[test]
+fn test_mutate_reports_point_mutations() {
+    use super::dna_base::DNABase;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let original = Genome::<DNABase>::random(&mut rng, 200);
+    let mut genome = original.clone();
+    let rates = MutationRates {
+        point: 0.1,
+        insertion: 0.0,
+        deletion: 0.0,
+        duplication: 0.0,
+        inversion: 0.0,
+        transposition: 0.0,
+        max_segment_length: 1,
+    };
+
+    let report = genome.mutate(&mut rng, &rates);
+    assert!(!report.is_empty());
+    assert_eq!(original.len(), genome.len());
+
+    let num_changed = original.iter().zip(genome.iter()).filter(|&(a, b)| a != b).count();
+    assert_eq!(report.mutations.len(), num_changed);
+    for mutation in report.mutations.iter() {
+        match *mutation {
+            Mutation::Point { position, from, to } => {
+                assert!(from != to);
+                assert_eq!(original[position], from);
+                assert_eq!(genome[position], to);
+            }
+            _ => panic!("unexpected mutation {:?}", mutation),
+        }
+    }
+}
+
+#[test]
+fn test_mutate_never_empties_genome() {
+    use super::dna_base::DNABase;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+    let mut genome = Genome::<DNABase>::random(&mut rng, 4);
+    let rates = MutationRates {
+        point: 0.0,
+        insertion: 0.0,
+        deletion: 1.0,
+        duplication: 0.0,
+        inversion: 0.0,
+        transposition: 0.0,
+        max_segment_length: 100,
+    };
+    for _ in 0..10 {
+        genome.mutate(&mut rng, &rates);
+        assert!(!genome.is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use super::{Base, Genome};
+use super::graph::Graph;
+use super::evolution::Development;
+use std::cmp;
+use std::cmp::Ordering;
+
+/// Maps a developed graph to a behaviour descriptor, a point in some
+/// (fixed dimensional) behaviour space.
+pub trait BehaviourDescriptor {
+    fn describe(&self, graph: &Graph) -> Vec<f64>;
+}
+
+impl<F> BehaviourDescriptor for F
+    where F: Fn(&Graph) -> Vec<f64>
+{
+    fn describe(&self, graph: &Graph) -> Vec<f64> {
+        self(graph)
+    }
+}
+
+/// Describes a graph by its node count, edge count, the histogram of the
+/// node degrees (in + out) and the histogram of the type counts of the nodes.
+/// The last bin of each histogram collects all larger values.
+#[derive(Debug, Clone)]
+pub struct GraphDescriptor {
+    pub max_degree: usize,
+    pub max_type_count: usize,
+}
+
+impl BehaviourDescriptor for GraphDescriptor {
+    fn describe(&self, graph: &Graph) -> Vec<f64> {
+        let mut degrees = vec![0; graph.nodes.len()];
+        for &(src, dst, _) in graph.edges.iter() {
+            degrees[src as usize] += 1;
+            degrees[dst as usize] += 1;
+        }
+
+        let mut degree_histogram = vec![0.0; self.max_degree + 1];
+        for &degree in degrees.iter() {
+            degree_histogram[cmp::min(degree, self.max_degree)] += 1.0;
+        }
+
+        let mut type_histogram = vec![0.0; self.max_type_count + 1];
+        for &(_, type_count) in graph.nodes.iter() {
+            type_histogram[cmp::min(type_count as usize, self.max_type_count)] += 1.0;
+        }
+
+        let mut behaviour = vec![graph.nodes.len() as f64, graph.edges.len() as f64];
+        behaviour.extend(degree_histogram);
+        behaviour.extend(type_histogram);
+        behaviour
+    }
+}
+
+/// Develops and describes ```genome```. Non-viable genomes are described
+/// like an empty graph.
+pub fn describe_genome<B, D, BD>(genome: &Genome<B>, development: &D, descriptor: &BD) -> Vec<f64>
+    where B: Base,
+          D: Development<B>,
+          BD: BehaviourDescriptor
+{
+    match development.develop(genome) {
+        Ok(graph) => descriptor.describe(&graph),
+        Err(_) => {
+            descriptor.describe(&Graph {
+                nodes: Vec::new(),
+                edges: Vec::new(),
+            })
+        }
+    }
+}
+
+/// Euclidean distance between two behaviours.
+pub fn distance(a: &[f64], b: &[f64]) -> f64 {
+    assert!(a.len() == b.len());
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+}
+
+/// Archive of behaviours that were novel when they were encountered.
+#[derive(Debug, Clone)]
+pub struct NoveltyArchive {
+    behaviours: Vec<Vec<f64>>,
+    // Number of nearest neighbours the novelty is averaged over.
+    pub k: usize,
+    // Behaviours with a novelty above this threshold are added to the archive.
+    pub threshold: f64,
+    // The oldest entries are dropped once the archive grows beyond this size.
+    pub max_size: Option<usize>,
+}
+
+impl NoveltyArchive {
+    pub fn new(k: usize, threshold: f64) -> NoveltyArchive {
+        assert!(k > 0);
+        NoveltyArchive {
+            behaviours: Vec::new(),
+            k,
+            threshold,
+            max_size: None,
+        }
+    }
+
+    pub fn behaviours(&self) -> &[Vec<f64>] {
+        &self.behaviours
+    }
+
+    pub fn len(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    /// The mean distance of ```behaviour``` to its ```k``` nearest neighbours
+    /// among ```population``` and the archive. The entry at ```skip``` in
+    /// ```population``` (the behaviour itself) is ignored.
+    pub fn novelty(&self, behaviour: &[f64], population: &[Vec<f64>], skip: Option<usize>) -> f64 {
+        let mut distances: Vec<f64> = population.iter()
+                                                .enumerate()
+                                                .filter(|&(i, _)| Some(i) != skip)
+                                                .map(|(_, other)| other)
+                                                .chain(self.behaviours.iter())
+                                                .map(|other| distance(behaviour, other))
+                                                .collect();
+        if distances.is_empty() {
+            return 0.0;
+        }
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let k = cmp::min(self.k, distances.len());
+        distances[..k].iter().sum::<f64>() / k as f64
+    }
+
+    /// Computes the novelty of every member of ```population``` and adds the
+    /// behaviours exceeding the threshold to the archive afterwards.
+    pub fn evaluate(&mut self, population: &[Vec<f64>]) -> Vec<f64> {
+        let novelty: Vec<f64> = population.iter()
+                                          .enumerate()
+                                          .map(|(i, b)| self.novelty(b, population, Some(i)))
+                                          .collect();
+
+        for (behaviour, &n) in population.iter().zip(novelty.iter()) {
+            if n > self.threshold {
+                self.behaviours.push(behaviour.clone());
+            }
+        }
+
+        if let Some(max_size) = self.max_size {
+            if self.behaviours.len() > max_size {
+                let excess = self.behaviours.len() - max_size;
+                self.behaviours.drain(..excess);
+            }
+        }
+
+        novelty
+    }
+}
+
+#[test]
+fn test_graph_descriptor() {
+    let graph = Graph {
+        nodes: vec![(1.0, 1), (1.0, 3), (0.5, 7)],
+        edges: vec![(0, 1, 1.0), (0, 2, 1.0), (1, 2, 1.0)],
+    };
+    let descriptor = GraphDescriptor {
+        max_degree: 2,
+        max_type_count: 3,
+    };
+    assert_eq!(vec![3.0, 3.0, 0.0, 0.0, 3.0, 0.0, 1.0, 0.0, 2.0],
+               descriptor.describe(&graph));
+}
+
+#[test]
+fn test_novelty_archive() {
+    let mut archive = NoveltyArchive::new(2, 1.5);
+    let population = vec![vec![0.0], vec![1.0], vec![2.0], vec![10.0]];
+
+    let novelty = archive.evaluate(&population);
+    assert_eq!(vec![1.5, 1.0, 1.5, 8.5], novelty);
+    assert_eq!(&[vec![10.0]], archive.behaviours());
+
+    // the archived behaviour now counts as a neighbour.
+    assert_eq!(0.5, archive.novelty(&[10.5], &[], None));
+}
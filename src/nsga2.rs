@@ -0,0 +1,198 @@
+// Multi-objective selection as in NSGA-II (Deb et al., 2002).
+//
+// Every individual is described by a vector of objective values, one
+// per objective. Like `evolution::Fitness`, all objectives are maximized;
+// negate an objective to minimize it.
+
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// True if ```a``` is at least as good as ```b``` in every objective and
+/// strictly better in at least one.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    assert!(a.len() == b.len());
+    let mut strictly_better = false;
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        if x < y {
+            return false;
+        }
+        if x > y {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Partitions the individuals into non-dominated fronts. The first front
+/// contains the indices of all individuals which are not dominated by any
+/// other, the second front those only dominated by the first front, etc.
+pub fn non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+
+    // ```dominated[i]``` are the individuals dominated by i.
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); n];
+    // ```domination_count[i]``` is the number of individuals dominating i.
+    let mut domination_count = vec![0; n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if dominates(&objectives[i], &objectives[j]) {
+                dominated[i].push(j);
+                domination_count[j] += 1;
+            } else if dominates(&objectives[j], &objectives[i]) {
+                dominated[j].push(i);
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..n).filter(|&i| domination_count[i] == 0).collect();
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+        for &i in front.iter() {
+            for &j in dominated[i].iter() {
+                domination_count[j] -= 1;
+                if domination_count[j] == 0 {
+                    next_front.push(j);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next_front;
+    }
+    fronts
+}
+
+// Adds the contribution of objective ```m``` to the crowding distance.
+fn add_crowding_distance(objectives: &[Vec<f64>],
+                         front: &[usize],
+                         m: usize,
+                         distance: &mut [f64]) {
+    let value = |k: usize| objectives[front[k]][m];
+    let mut order: Vec<usize> = (0..front.len()).collect();
+    order.sort_by(|&a, &b| value(a).partial_cmp(&value(b)).unwrap_or(Ordering::Equal));
+
+    let first = order[0];
+    let last = order[order.len() - 1];
+    distance[first] = f64::INFINITY;
+    distance[last] = f64::INFINITY;
+
+    let range = value(last) - value(first);
+    if range <= 0.0 {
+        return;
+    }
+    for w in order.windows(3) {
+        distance[w[1]] += (value(w[2]) - value(w[0])) / range;
+    }
+}
+
+/// The crowding distance of each member of ```front``` (in the same order).
+/// Boundary individuals get an infinite distance.
+pub fn crowding_distance(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let mut distance = vec![0.0; front.len()];
+    if front.is_empty() {
+        return distance;
+    }
+
+    for m in 0..objectives[front[0]].len() {
+        add_crowding_distance(objectives, front, m, &mut distance);
+    }
+    distance
+}
+
+/// Pareto rank and crowding distance of every individual.
+#[derive(Debug, Clone)]
+pub struct Ranking {
+    // The index of the front (0 is the best).
+    pub rank: Vec<usize>,
+    pub crowding_distance: Vec<f64>,
+}
+
+impl Ranking {
+    pub fn new(objectives: &[Vec<f64>]) -> Ranking {
+        let n = objectives.len();
+        let mut ranking = Ranking {
+            rank: vec![0; n],
+            crowding_distance: vec![0.0; n],
+        };
+        for (r, front) in non_dominated_sort(objectives).iter().enumerate() {
+            let distance = crowding_distance(objectives, front);
+            for (&i, &d) in front.iter().zip(distance.iter()) {
+                ranking.rank[i] = r;
+                ranking.crowding_distance[i] = d;
+            }
+        }
+        ranking
+    }
+
+    /// The crowded-comparison operator. ```Ordering::Less``` means that
+    /// ```a``` is better than ```b```.
+    pub fn crowded_cmp(&self, a: usize, b: usize) -> Ordering {
+        self.rank[a].cmp(&self.rank[b]).then_with(|| {
+            self.crowding_distance[b]
+                .partial_cmp(&self.crowding_distance[a])
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+
+    /// Binary tournament using the crowded-comparison operator.
+    pub fn tournament<R: Rng>(&self, rng: &mut R) -> usize {
+        let n = self.rank.len();
+        let a = rng.gen_range(0, n);
+        let b = rng.gen_range(0, n);
+        if self.crowded_cmp(b, a) == Ordering::Less {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// Selects the indices of the ```n``` best individuals, taking whole fronts
+/// and breaking up the last front by crowding distance.
+pub fn select(objectives: &[Vec<f64>], n: usize) -> Vec<usize> {
+    let mut selected = Vec::with_capacity(n);
+    for front in non_dominated_sort(objectives) {
+        if selected.len() + front.len() <= n {
+            selected.extend(front);
+        } else {
+            let distance = crowding_distance(objectives, &front);
+            let mut order: Vec<usize> = (0..front.len()).collect();
+            order.sort_by(|&a, &b| {
+                distance[b].partial_cmp(&distance[a]).unwrap_or(Ordering::Equal)
+            });
+            let remaining = n - selected.len();
+            selected.extend(order.iter().take(remaining).map(|&k| front[k]));
+        }
+        if selected.len() >= n {
+            break;
+        }
+    }
+    selected
+}
+
+#[test]
+fn test_non_dominated_sort() {
+    let objectives = vec![vec![1.0, 1.0],
+                          vec![2.0, 2.0],
+                          vec![3.0, 0.0],
+                          vec![0.0, 3.0],
+                          vec![1.0, 0.5]];
+    let fronts = non_dominated_sort(&objectives);
+    assert_eq!(vec![vec![1, 2, 3], vec![0], vec![4]], fronts);
+}
+
+#[test]
+fn test_select_prefers_boundary_of_front() {
+    let objectives = vec![vec![0.0, 4.0], vec![1.0, 3.0], vec![1.1, 2.9], vec![4.0, 0.0]];
+    let mut selected = select(&objectives, 3);
+    selected.sort();
+    assert_eq!(3, selected.len());
+    assert!(selected.contains(&0));
+    assert!(selected.contains(&3));
+
+    let ranking = Ranking::new(&objectives);
+    assert!(ranking.rank.iter().all(|&r| r == 0));
+    assert_eq!(Ordering::Less, ranking.crowded_cmp(0, 1));
+}
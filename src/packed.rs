@@ -0,0 +1,381 @@
+// Compact storage of base strings over alphabets with at most four
+// symbols. Four bases are packed into each byte.
+
+use super::{Base, BaseString, Genome};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Bases that can be encoded in two bits, i.e. alphabets with at most four
+/// symbols. By default the bits are the index of the base, and the default
+/// methods panic for larger alphabets.
+pub trait PackedBase: Base {
+    /// Returns a value in 0..4.
+    fn to_bits(self) -> u8 {
+        assert!(Self::cardinality() <= 4);
+        self.index() as u8
+    }
+
+    /// The inverse of `to_bits`. Only the lower two bits are used.
+    fn from_bits(bits: u8) -> Self {
+        assert!(Self::cardinality() <= 4);
+        Self::from_index((bits & 3) as usize)
+    }
+}
+
+// Patterns up to this length are matched as a single integer.
+const MAX_FAST_PATTERN: usize = 32;
+
+#[inline]
+fn get_bits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i >> 2] >> ((i & 3) * 2)) & 3
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PackedBaseString<B: PackedBase> {
+    bytes: Vec<u8>,
+    len: usize,
+    _base: PhantomData<B>,
+}
+
+impl<B: PackedBase> PackedBaseString<B> {
+    pub fn new() -> PackedBaseString<B> {
+        PackedBaseString {
+            bytes: Vec::new(),
+            len: 0,
+            _base: PhantomData,
+        }
+    }
+
+    pub fn from_slice(bases: &[B]) -> PackedBaseString<B> {
+        let mut packed = PackedBaseString {
+            bytes: Vec::with_capacity(bases.len().div_ceil(4)),
+            len: 0,
+            _base: PhantomData,
+        };
+        for &base in bases {
+            packed.push(base);
+        }
+        packed
+    }
+
+    pub fn push(&mut self, base: B) {
+        let shift = (self.len & 3) * 2;
+        if shift == 0 {
+            self.bytes.push(0);
+        }
+        *self.bytes.last_mut().unwrap() |= (base.to_bits() & 3) << shift;
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> B {
+        self.as_slice().get(i)
+    }
+
+    pub fn as_slice<'a>(&'a self) -> PackedSlice<'a, B> {
+        PackedSlice {
+            bytes: &self.bytes,
+            start: 0,
+            len: self.len,
+            _base: PhantomData,
+        }
+    }
+
+    /// The bases in ```[start, end)```.
+    pub fn slice<'a>(&'a self, start: usize, end: usize) -> PackedSlice<'a, B> {
+        self.as_slice().slice(start, end)
+    }
+
+    pub fn iter<'a>(&'a self) -> PackedIter<'a, B> {
+        self.as_slice().iter()
+    }
+
+    /// Memory used by the packed bases in bytes.
+    pub fn num_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn to_base_string(&self) -> BaseString<B> {
+        BaseString { v: self.iter().collect() }
+    }
+
+    pub fn to_genome(&self) -> Genome<B> {
+        Genome::from_vec(self.iter().collect())
+    }
+
+    /// Splits the packed string into genes of ```length_of_gene``` bases,
+    /// each preceded by an exact match of ```promoter```. This is the linear,
+    /// forward-strand reading with default settings of `Genome::iter_genes`;
+    /// mismatches, terminators, circular genomes and the reverse strand are
+    /// not supported.
+    pub fn iter_genes<'a, 'b>(&'a self,
+                              promoter: &'b [B],
+                              length_of_gene: usize)
+                              -> PackedGeneIterator<'a, 'b, B> {
+        PackedGeneIterator {
+            length_of_gene,
+            sequence: self.as_slice(),
+            promoter,
+        }
+    }
+}
+
+impl<B: PackedBase> Default for PackedBaseString<B> {
+    fn default() -> PackedBaseString<B> {
+        PackedBaseString::new()
+    }
+}
+
+impl<B: PackedBase> fmt::Debug for PackedBaseString<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for base in self.iter() {
+            write!(f, "{:?}", base)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<B: PackedBase> Genome<B> {
+    pub fn pack(&self) -> PackedBaseString<B> {
+        PackedBaseString::from_slice(self)
+    }
+}
+
+/// A borrowed range of a `PackedBaseString`.
+#[derive(Clone, Copy)]
+pub struct PackedSlice<'a, B: PackedBase + 'a> {
+    bytes: &'a [u8],
+    start: usize,
+    len: usize,
+    _base: PhantomData<B>,
+}
+
+impl<'a, B: PackedBase + 'a> PackedSlice<'a, B> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> B {
+        assert!(i < self.len);
+        B::from_bits(get_bits(self.bytes, self.start + i))
+    }
+
+    /// The bases in ```[start, end)``` of this slice.
+    pub fn slice(&self, start: usize, end: usize) -> PackedSlice<'a, B> {
+        assert!(start <= end && end <= self.len);
+        PackedSlice {
+            bytes: self.bytes,
+            start: self.start + start,
+            len: end - start,
+            _base: PhantomData,
+        }
+    }
+
+    pub fn iter(&self) -> PackedIter<'a, B> {
+        PackedIter {
+            slice: *self,
+            pos: 0,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<B> {
+        self.iter().collect()
+    }
+
+    // Calls ```f``` with the start position of every (possibly overlapping)
+    // occurrence of ```pattern``` until ```f``` returns false.
+    fn find_each<F>(&self, pattern: &[B], mut f: F)
+        where F: FnMut(usize) -> bool
+    {
+        let m = pattern.len();
+        assert!(m > 0);
+        if m > self.len {
+            return;
+        }
+
+        if m > MAX_FAST_PATTERN {
+            for i in 0..(self.len - m + 1) {
+                if pattern.iter().enumerate().all(|(k, &b)| self.get(i + k) == b) && !f(i) {
+                    return;
+                }
+            }
+            return;
+        }
+
+        // The window holds the last ```m``` bases, the oldest in the lowest bits.
+        let code = pattern.iter()
+                          .enumerate()
+                          .fold(0u64, |c, (k, b)| c | ((b.to_bits() as u64 & 3) << (2 * k)));
+        let top = 2 * (m - 1);
+        let mut window = 0u64;
+        for j in 0..self.len {
+            window = (window >> 2) | ((get_bits(self.bytes, self.start + j) as u64) << top);
+            if j + 1 >= m && window == code && !f(j + 1 - m) {
+                return;
+            }
+        }
+    }
+
+    /// Position of the first occurrence of ```pattern```.
+    pub fn locate(&self, pattern: &[B]) -> Option<usize> {
+        let mut found = None;
+        self.find_each(pattern, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    /// Number of (possibly overlapping) occurrences of ```pattern```.
+    pub fn count(&self, pattern: &[B]) -> usize {
+        let mut cnt = 0;
+        self.find_each(pattern, |_| {
+            cnt += 1;
+            true
+        });
+        cnt
+    }
+}
+
+pub struct PackedIter<'a, B: PackedBase + 'a> {
+    slice: PackedSlice<'a, B>,
+    pos: usize,
+}
+
+impl<'a, B: PackedBase + 'a> Iterator for PackedIter<'a, B> {
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.pos < self.slice.len {
+            let base = self.slice.get(self.pos);
+            self.pos += 1;
+            Some(base)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.slice.len - self.pos;
+        (n, Some(n))
+    }
+}
+
+/// The packed counterpart of `Gene`, without a location.
+pub struct PackedGene<'a, B: PackedBase + 'a> {
+    pub regulatory_region: PackedSlice<'a, B>,
+    pub gene: PackedSlice<'a, B>,
+}
+
+impl<'a, B: PackedBase + 'a> PackedGene<'a, B> {
+    /// The gene product
+    pub fn product(&self) -> BaseString<B> {
+        BaseString { v: self.gene.iter().map(|b| b.succ()).collect() }
+    }
+
+    pub fn find_product_in_regulatory_region(&self, product: &[B]) -> bool {
+        self.regulatory_region.locate(product).is_some()
+    }
+
+    pub fn count_product_in_regulatory_region(&self, product: &[B]) -> usize {
+        self.regulatory_region.count(product)
+    }
+}
+
+pub struct PackedGeneIterator<'a, 'b, B: PackedBase + 'a + 'b> {
+    length_of_gene: usize,
+    sequence: PackedSlice<'a, B>,
+    promoter: &'b [B],
+}
+
+impl<'a, 'b, B: PackedBase + 'a + 'b> Iterator for PackedGeneIterator<'a, 'b, B> {
+    type Item = PackedGene<'a, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.sequence.locate(self.promoter)?;
+        let gene_start = pos + self.promoter.len();
+        let gene_end = gene_start + self.length_of_gene;
+
+        // gene is not complete
+        if gene_end > self.sequence.len() {
+            return None;
+        }
+
+        let gene = PackedGene {
+            regulatory_region: self.sequence.slice(0, pos),
+            gene: self.sequence.slice(gene_start, gene_end),
+        };
+        self.sequence = self.sequence.slice(gene_end, self.sequence.len());
+        Some(gene)
+    }
+}
+
+#[test]
+fn test_pack_round_trip() {
+    use super::dna_base::DNABase;
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<DNABase>::random(&mut rng, 1001);
+    let packed = genome.pack();
+    assert_eq!(1001, packed.len());
+    assert_eq!(251, packed.num_bytes());
+    assert_eq!(genome, packed.to_genome());
+    assert_eq!(&genome[10..20], &packed.slice(10, 20).to_vec()[..]);
+    assert_eq!(&genome[13..17], &packed.slice(10, 20).slice(3, 7).to_vec()[..]);
+}
+
+#[test]
+fn test_packed_search() {
+    use super::base4::Base4;
+    use super::{locate_substr, count_substr};
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<Base4>::random(&mut rng, 2000);
+    let packed = genome.pack();
+
+    for &m in [1, 3, 4, 32, 33].iter() {
+        for start in [0, 7, 500].iter() {
+            let pattern = &genome[*start..*start + m];
+            let slice = packed.slice(5, 1999);
+            assert_eq!(locate_substr(&genome[5..1999], pattern), slice.locate(pattern));
+            assert_eq!(count_substr(&genome[5..1999], pattern), slice.count(pattern));
+        }
+    }
+}
+
+#[test]
+fn test_packed_iter_genes() {
+    use super::base4::{Base4, B0, B1};
+    use rand::{SeedableRng, XorShiftRng};
+
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let genome = Genome::<Base4>::random(&mut rng, 4000);
+    let packed = genome.pack();
+    let promoter = [B0, B1, B0, B1];
+
+    let genes: Vec<_> = genome.iter_genes(&promoter, 4).collect();
+    let packed_genes: Vec<_> = packed.iter_genes(&promoter, 4).collect();
+    assert!(!genes.is_empty());
+    assert_eq!(genes.len(), packed_genes.len());
+    for (gene, packed_gene) in genes.iter().zip(packed_genes.iter()) {
+        assert_eq!(&gene.regulatory_region[..], &packed_gene.regulatory_region.to_vec()[..]);
+        assert_eq!(&gene.gene[..], &packed_gene.gene.to_vec()[..]);
+        let product = gene.product();
+        assert_eq!(gene.count_product_in_regulatory_region(&product),
+                   packed_gene.count_product_in_regulatory_region(&product));
+    }
+}
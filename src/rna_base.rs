@@ -0,0 +1,166 @@
+// RNA bases and the transcription of DNA genes into RNA.
+
+use super::{Base, BaseString, locate_substr};
+use super::dna_base::DNABase;
+use super::packed::PackedBase;
+use super::translation::Translation;
+use rand::{Rand, Rng};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[repr(u8)]
+pub enum RNABase {
+    A,
+    U,
+    G,
+    C,
+}
+
+impl Rand for RNABase {
+    fn rand<R: Rng>(rng: &mut R) -> Self {
+        RNABase::from_index(rng.gen_range(0, RNABase::cardinality()))
+    }
+}
+
+impl Base for RNABase {
+    fn succ(self) -> Self {
+        match self {
+            RNABase::A => RNABase::U,
+            RNABase::U => RNABase::G,
+            RNABase::G => RNABase::C,
+            RNABase::C => RNABase::A,
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(RNABase::A),
+            'U' => Some(RNABase::U),
+            'G' => Some(RNABase::G),
+            'C' => Some(RNABase::C),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            RNABase::A => 'A',
+            RNABase::U => 'U',
+            RNABase::G => 'G',
+            RNABase::C => 'C',
+        }
+    }
+
+    fn complement(self) -> Self {
+        match self {
+            RNABase::A => RNABase::U,
+            RNABase::U => RNABase::A,
+            RNABase::G => RNABase::C,
+            RNABase::C => RNABase::G,
+        }
+    }
+
+    fn cardinality() -> usize {
+        4
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => RNABase::A,
+            1 => RNABase::U,
+            2 => RNABase::G,
+            3 => RNABase::C,
+            _ => panic!("invalid index {}", index),
+        }
+    }
+}
+
+impl PackedBase for RNABase {}
+
+/// The RNA base corresponding to a base of the coding strand (T becomes U).
+pub fn transcribe_base(base: DNABase) -> RNABase {
+    match base {
+        DNABase::A => RNABase::A,
+        DNABase::T => RNABase::U,
+        DNABase::G => RNABase::G,
+        DNABase::C => RNABase::C,
+    }
+}
+
+/// Transcribes genes into RNA. Optionally, introns are spliced out of the
+/// transcript.
+#[derive(Debug, Clone, Default)]
+pub struct Transcription<'a> {
+    // An intron runs from an occurrence of the first marker up to and
+    // including the next occurrence of the second marker. Both markers are
+    // non-empty.
+    intron_markers: Option<(&'a [RNABase], &'a [RNABase])>,
+}
+
+impl<'a> Transcription<'a> {
+    /// Transcription without splicing.
+    pub fn new() -> Transcription<'a> {
+        Transcription { intron_markers: None }
+    }
+
+    /// Transcription removing the introns delimited by ```start``` and ```end```.
+    pub fn spliced(start: &'a [RNABase], end: &'a [RNABase]) -> Transcription<'a> {
+        assert!(!start.is_empty() && !end.is_empty());
+        Transcription { intron_markers: Some((start, end)) }
+    }
+
+    /// The start and end marker of introns, ```None``` if nothing is spliced.
+    pub fn intron_markers(&self) -> Option<(&'a [RNABase], &'a [RNABase])> {
+        self.intron_markers
+    }
+
+    // Removes all introns from ```rna```.
+    fn splice(&self, rna: &mut Vec<RNABase>) {
+        let (start, end) = match self.intron_markers {
+            Some(markers) => markers,
+            None => return,
+        };
+        let mut pos = 0;
+        while let Some(i) = locate_substr(&rna[pos..], start) {
+            let intron_start = pos + i;
+            let search_from = intron_start + start.len();
+            match locate_substr(&rna[search_from..], end) {
+                Some(j) => {
+                    rna.drain(intron_start..search_from + j + end.len());
+                    pos = intron_start;
+                }
+                // an intron without end is kept.
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a> Translation<DNABase> for Transcription<'a> {
+    type Output = RNABase;
+
+    fn translate(&self, gene: &[DNABase]) -> BaseString<RNABase> {
+        let mut rna: Vec<RNABase> = gene.iter().map(|&b| transcribe_base(b)).collect();
+        self.splice(&mut rna);
+        BaseString { v: rna }
+    }
+}
+
+#[test]
+fn test_transcription() {
+    let gene: BaseString<DNABase> = "ATG GTAAAG CCT GTTAG T".parse().unwrap();
+    assert_eq!("AUGGUAAAGCCUGUUAGU", Transcription::new().translate(&gene).to_string());
+
+    let start: BaseString<RNABase> = "GU".parse().unwrap();
+    let end: BaseString<RNABase> = "AG".parse().unwrap();
+    let transcription = Transcription::spliced(&start, &end);
+    assert_eq!("AUGCCUU", transcription.translate(&gene).to_string());
+
+    // the last intron has no end.
+    let gene: BaseString<DNABase> = "CC GTAG CC GTCC".parse().unwrap();
+    assert_eq!("CCCCGUCC", transcription.translate(&gene).to_string());
+    assert!(Transcription::new().intron_markers().is_none());
+}
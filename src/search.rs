@@ -0,0 +1,88 @@
+// Searching many patterns at once.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Maps each pattern of a given length to the ids of the patterns.
+type PatternTable<T> = HashMap<Vec<T>, Vec<usize>>;
+
+/// An index over a set of patterns, which finds the occurrences of all
+/// patterns in a single pass over the searched sequence.
+///
+/// Patterns are grouped by length, and every window of each length is
+/// looked up in a hash table. For the few distinct pattern lengths used
+/// for gene products this is O(n * m) for a sequence of length n and
+/// pattern length m, independent of the number of patterns.
+#[derive(Debug, Clone)]
+pub struct PatternIndex<T: Eq + Hash> {
+    // (pattern length, patterns of that length)
+    by_length: Vec<(usize, PatternTable<T>)>,
+    num_patterns: usize,
+}
+
+impl<T: Eq + Hash + Clone> PatternIndex<T> {
+    /// The id of a pattern is its position in ```patterns```. Equal
+    /// patterns are allowed and match at the same positions.
+    pub fn new<P: AsRef<[T]>>(patterns: &[P]) -> PatternIndex<T> {
+        let mut by_length: Vec<(usize, PatternTable<T>)> = Vec::new();
+        for (id, pattern) in patterns.iter().enumerate() {
+            let pattern = pattern.as_ref();
+            assert!(!pattern.is_empty());
+            let pos = match by_length.iter().position(|&(len, _)| len == pattern.len()) {
+                Some(pos) => pos,
+                None => {
+                    by_length.push((pattern.len(), HashMap::new()));
+                    by_length.len() - 1
+                }
+            };
+            by_length[pos].1.entry(pattern.to_vec()).or_default().push(id);
+        }
+        PatternIndex {
+            by_length,
+            num_patterns: patterns.len(),
+        }
+    }
+
+    pub fn num_patterns(&self) -> usize {
+        self.num_patterns
+    }
+
+    /// Calls ```f(id, position)``` for every (possibly overlapping) occurrence
+    /// of every pattern in ```s```.
+    pub fn for_each_match<F>(&self, s: &[T], mut f: F)
+        where F: FnMut(usize, usize)
+    {
+        for &(len, ref patterns) in self.by_length.iter() {
+            if s.len() < len {
+                continue;
+            }
+            for (position, window) in s.windows(len).enumerate() {
+                if let Some(ids) = patterns.get(window) {
+                    for &id in ids.iter() {
+                        f(id, position);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The number of occurrences of each pattern in ```s```, indexed by id.
+    pub fn count_all(&self, s: &[T]) -> Vec<usize> {
+        let mut counts = vec![0; self.num_patterns];
+        self.for_each_match(s, |id, _| counts[id] += 1);
+        counts
+    }
+}
+
+#[test]
+fn test_pattern_index() {
+    let patterns: Vec<&[u8]> = vec![b"ab", b"aba", b"ab", b"x"];
+    let index = PatternIndex::new(&patterns);
+    assert_eq!(vec![3, 2, 3, 0], index.count_all(b"ababab"));
+    assert_eq!(vec![0, 0, 0, 1], index.count_all(b"x"));
+
+    let mut matches = Vec::new();
+    index.for_each_match(b"aba", |id, pos| matches.push((id, pos)));
+    matches.sort();
+    assert_eq!(vec![(0, 0), (1, 0), (2, 0)], matches);
+}
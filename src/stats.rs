@@ -0,0 +1,139 @@
+// Statistics on the composition of genomes, e.g. to understand why a
+// random genome contains few or many genes.
+
+use super::{Base, Genome, count_substr};
+use std::collections::HashMap;
+
+/// The number of occurrences of each symbol, indexed by `Base::index`.
+pub fn symbol_counts<B: Base>(s: &[B]) -> Vec<usize> {
+    let mut counts = vec![0; B::cardinality()];
+    for base in s.iter() {
+        counts[base.index()] += 1;
+    }
+    counts
+}
+
+/// The relative frequency of each symbol, indexed by `Base::index`. All
+/// frequencies of an empty sequence are 0.
+pub fn symbol_frequencies<B: Base>(s: &[B]) -> Vec<f64> {
+    let n = s.len();
+    symbol_counts(s).into_iter()
+                    .map(|count| if n == 0 { 0.0 } else { count as f64 / n as f64 })
+                    .collect()
+}
+
+/// The number of occurrences of every (overlapping) substring of length
+/// ```k``` occurring in ```s```.
+pub fn kmer_spectrum<B: Base>(s: &[B], k: usize) -> HashMap<Vec<B>, usize> {
+    assert!(k > 0);
+    let mut spectrum = HashMap::new();
+    for window in s.windows(k) {
+        *spectrum.entry(window.to_vec()).or_insert(0) += 1;
+    }
+    spectrum
+}
+
+// Shannon entropy in bits of the distribution given by ```counts```.
+fn entropy_of_counts<I: Iterator<Item = usize>>(counts: I) -> f64 {
+    let counts: Vec<usize> = counts.filter(|&count| count > 0).collect();
+    let total: usize = counts.iter().sum();
+    counts.iter()
+          .map(|&count| {
+              let p = count as f64 / total as f64;
+              -p * p.log2()
+          })
+          .sum()
+}
+
+/// Shannon entropy (in bits per symbol) of the symbol distribution. At most
+/// log2 of the cardinality of the alphabet.
+pub fn entropy<B: Base>(s: &[B]) -> f64 {
+    entropy_of_counts(symbol_counts(s).into_iter())
+}
+
+/// Shannon entropy (in bits) of the distribution of substrings of length
+/// ```k```.
+pub fn kmer_entropy<B: Base>(s: &[B], k: usize) -> f64 {
+    entropy_of_counts(kmer_spectrum(s, k).into_values())
+}
+
+/// Number of (possibly overlapping) occurrences of ```promoter```.
+pub fn promoter_count<B: Base>(s: &[B], promoter: &[B]) -> usize {
+    count_substr(s, promoter)
+}
+
+/// Occurrences of ```promoter``` per base.
+pub fn promoter_density<B: Base>(s: &[B], promoter: &[B]) -> f64 {
+    if s.is_empty() {
+        return 0.0;
+    }
+    promoter_count(s, promoter) as f64 / s.len() as f64
+}
+
+/// The expected number of occurrences of a promoter of length
+/// ```promoter_length``` in a uniformly random sequence of length ```n```.
+pub fn expected_promoter_count<B: Base>(n: usize, promoter_length: usize) -> f64 {
+    if n < promoter_length {
+        return 0.0;
+    }
+    (n - promoter_length + 1) as f64 / (B::cardinality() as f64).powi(promoter_length as i32)
+}
+
+/// Summary of the composition of a genome with respect to a promoter.
+#[derive(Debug, Clone)]
+pub struct Composition {
+    pub length: usize,
+    pub frequencies: Vec<f64>,
+    pub entropy: f64,
+    pub promoter_count: usize,
+    pub promoter_density: f64,
+    pub expected_promoter_count: f64,
+    // Genes as found by ```Genome::iter_genes```.
+    pub gene_count: usize,
+}
+
+impl Composition {
+    pub fn of<B: Base>(genome: &Genome<B>, promoter: &[B], length_of_gene: usize) -> Composition {
+        Composition {
+            length: genome.len(),
+            frequencies: symbol_frequencies(genome),
+            entropy: entropy(genome),
+            promoter_count: promoter_count(genome, promoter),
+            promoter_density: promoter_density(genome, promoter),
+            expected_promoter_count: expected_promoter_count::<B>(genome.len(), promoter.len()),
+            gene_count: genome.iter_genes(promoter, length_of_gene).count(),
+        }
+    }
+}
+
+#[test]
+fn test_symbol_statistics() {
+    use super::dna_base::DNABase;
+
+    let genome: Genome<DNABase> = "AAAA TTGC".parse().unwrap();
+    assert_eq!(vec![4, 2, 1, 1], symbol_counts(&genome));
+    assert_eq!(vec![0.5, 0.25, 0.125, 0.125], symbol_frequencies(&genome));
+    assert_eq!(1.75, entropy(&genome));
+    assert_eq!(0.0, entropy(&genome[..4]));
+
+    let spectrum = kmer_spectrum(&genome, 2);
+    assert_eq!(Some(&3), spectrum.get(&vec![DNABase::A, DNABase::A]));
+    assert_eq!(5, spectrum.len());
+    assert_eq!(7, spectrum.values().sum::<usize>());
+    assert_eq!(1.0, kmer_entropy(&genome[3..6], 2));
+}
+
+#[test]
+fn test_composition() {
+    use super::base4::{Base4, B0, B1};
+
+    let promoter = [B0, B1, B0, B1];
+    let genome: Genome<Base4> = "22 010101 3333 2 0101 3232 0101 22".parse().unwrap();
+    let composition = Composition::of(&genome, &promoter, 4);
+    assert_eq!(27, composition.length);
+    assert_eq!(4, composition.promoter_count);
+    assert_eq!(4.0 / 27.0, composition.promoter_density);
+    assert_eq!(24.0 / 256.0, composition.expected_promoter_count);
+    assert_eq!(2, composition.gene_count);
+    assert_eq!(4, composition.frequencies.len());
+}
@@ -0,0 +1,102 @@
+// Translation of genes into gene products.
+
+use super::{Base, BaseString};
+use std::collections::HashMap;
+
+/// Translates the bases of a gene into its product. The product may use a
+/// different alphabet than the genome. A `Binding` between both alphabets
+/// relates such products to regulatory regions in a gene network.
+pub trait Translation<B: Base> {
+    type Output: Base;
+
+    fn translate(&self, gene: &[B]) -> BaseString<Self::Output>;
+}
+
+/// Maps each base to its successor (`Base::succ`). This is the translation
+/// used by `Gene::product`.
+#[derive(Debug, Clone, Copy)]
+pub struct Successor;
+
+impl<B: Base> Translation<B> for Successor {
+    type Output = B;
+
+    fn translate(&self, gene: &[B]) -> BaseString<B> {
+        BaseString { v: gene.iter().map(|b| b.succ()).collect() }
+    }
+}
+
+/// Maps each base independently through a function.
+#[derive(Debug, Clone, Copy)]
+pub struct PerBase<F>(pub F);
+
+impl<B, P, F> Translation<B> for PerBase<F>
+    where B: Base,
+          P: Base,
+          F: Fn(B) -> P
+{
+    type Output = P;
+
+    fn translate(&self, gene: &[B]) -> BaseString<P> {
+        BaseString { v: gene.iter().map(|&b| (self.0)(b)).collect() }
+    }
+}
+
+/// Maps each codon (a group of ```codon_length``` consecutive bases) of a
+/// gene to one symbol. Codons missing in the table and an incomplete codon
+/// at the end of the gene are skipped.
+#[derive(Debug, Clone)]
+pub struct CodonTable<B: Base, P: Base> {
+    codon_length: usize,
+    table: HashMap<Vec<B>, P>,
+}
+
+impl<B: Base, P: Base> CodonTable<B, P> {
+    pub fn new(codon_length: usize) -> CodonTable<B, P> {
+        assert!(codon_length > 0);
+        CodonTable {
+            codon_length,
+            table: HashMap::new(),
+        }
+    }
+
+    pub fn codon_length(&self) -> usize {
+        self.codon_length
+    }
+
+    pub fn insert(&mut self, codon: &[B], symbol: P) {
+        assert!(codon.len() == self.codon_length);
+        self.table.insert(codon.to_vec(), symbol);
+    }
+
+    pub fn get(&self, codon: &[B]) -> Option<P> {
+        self.table.get(codon).cloned()
+    }
+}
+
+impl<B: Base, P: Base> Translation<B> for CodonTable<B, P> {
+    type Output = P;
+
+    fn translate(&self, gene: &[B]) -> BaseString<P> {
+        BaseString {
+            v: gene.chunks(self.codon_length)
+                   .filter_map(|codon| self.get(codon))
+                   .collect(),
+        }
+    }
+}
+
+#[test]
+fn test_translations() {
+    use super::base4::{Base4, B0, B1, B2, B3};
+    use super::dna_base::DNABase;
+
+    let gene: BaseString<Base4> = "01 23 11 0".parse().unwrap();
+    assert_eq!(&[B1, B2, B3, B0, B2, B2, B1], &Successor.translate(&gene)[..]);
+    assert_eq!(&[B3, B2, B1, B0, B2, B2, B3],
+               &PerBase(|b: Base4| b.complement()).translate(&gene)[..]);
+
+    let mut table = CodonTable::new(2);
+    table.insert(&[B0, B1], DNABase::G);
+    table.insert(&[B1, B1], DNABase::A);
+    assert_eq!(&[DNABase::G, DNABase::A], &table.translate(&gene)[..]);
+}